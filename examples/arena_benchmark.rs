@@ -0,0 +1,30 @@
+//! compares a list that boxes every node with a list that allocates its nodes from an arena
+//!
+//! run with `cargo run --release --example arena_benchmark`
+
+use std::{hint::black_box, time::Instant};
+
+use xor_linked_list::XorLinkedList;
+
+const ELEMENTS: usize = 10_000_000;
+
+/// pushes, sums and drops the elements of the list, returns the elapsed time in milliseconds
+fn run(name: &str, mut list: XorLinkedList<usize>) -> u128 {
+    let start = Instant::now();
+    for i in 0..ELEMENTS {
+        list.push_back(i);
+    }
+    let sum: usize = list.iter().sum();
+    black_box(sum);
+    drop(list);
+    let elapsed = start.elapsed().as_millis();
+    println!("{name}: {elapsed}ms");
+
+    elapsed
+}
+
+fn main() {
+    let boxed = run("boxed nodes", XorLinkedList::new());
+    let arena = run("arena nodes", XorLinkedList::with_capacity(ELEMENTS));
+    println!("speedup: {:.2}x", boxed as f64 / arena.max(1) as f64);
+}
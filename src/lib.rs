@@ -1,24 +1,44 @@
-use std::{
-    fmt::Debug,
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+use alloc::{boxed::Box, rc::Rc, string::String, vec, vec::Vec};
+use core::{
+    cell::RefCell,
+    cmp::Ordering,
+    error::Error,
+    fmt::{Debug, Display, Write},
     hash::Hash,
+    iter::{Sum, from_fn, once},
     marker::PhantomData,
-    mem::swap,
-    ops::{Index, IndexMut},
-    ptr::null_mut,
+    mem::{MaybeUninit, replace, size_of, swap, take},
+    ops::{Bound, Index, IndexMut, RangeBounds},
+    panic::{RefUnwindSafe, UnwindSafe},
+    ptr::{addr_of, null_mut, with_exposed_provenance_mut},
+};
+#[cfg(feature = "std")]
+use std::{
+    collections::{HashMap, HashSet},
+    hash::{BuildHasher, RandomState},
 };
 
 #[cfg(feature = "serde")]
-use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde::{
+    Deserialize, Deserializer, Serialize, Serializer,
+    de::{SeqAccess, Visitor},
+};
 
 const INDEX_BOUNDS_ERROR: &str = "Index is out of bounds";
+const RANGE_BOUNDS_ERROR: &str = "Range is out of bounds";
 
-/// performs XOR on 2 pointers and returns the resulting pointer
+/// performs XOR on 2 pointers and returns the resulting pointer,
+/// the result picks up the provenance of the exposed node allocation at its address
 #[inline]
 fn xor_ptrs<T>(first_ptr: *mut XorNode<T>, second_ptr: *mut XorNode<T>) -> *mut XorNode<T> {
-    let first_ptr_value = first_ptr as usize;
-    let second_ptr_value = second_ptr as usize;
+    let first_ptr_value = first_ptr.expose_provenance();
+    let second_ptr_value = second_ptr.expose_provenance();
     let new_ptr_value = first_ptr_value ^ second_ptr_value;
-    new_ptr_value as _
+    with_exposed_provenance_mut(new_ptr_value)
 }
 
 struct XorNode<T> {
@@ -26,11 +46,341 @@ struct XorNode<T> {
     xor_ptr: *mut XorNode<T>,
 }
 impl<T> XorNode<T> {
+    /// allocates a node and exposes its provenance, so that it can be recovered from a XOR pointer
     fn allocate(value: T) -> *mut Self {
-        Box::leak(Box::new(Self {
+        let node = Box::into_raw(Box::new(Self {
             payload: value,
             xor_ptr: null_mut(),
-        }))
+        }));
+        node.expose_provenance();
+
+        node
+    }
+}
+
+#[cfg(feature = "debug-internals")]
+impl<T: Debug> Debug for XorNode<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("XorNode")
+            .field("address", &(self as *const Self))
+            .field("xor_ptr", &self.xor_ptr)
+            .field("payload", &self.payload)
+            .finish()
+    }
+}
+
+/// formats the nodes of a list from the start to the end
+#[cfg(feature = "debug-internals")]
+struct DebugNodes<'a, T>(&'a XorLinkedList<T>);
+#[cfg(feature = "debug-internals")]
+impl<T: Debug> Debug for DebugNodes<'_, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut prev_ptr = null_mut();
+        let mut current_ptr = self.0.start;
+        let nodes = from_fn(|| {
+            let node = unsafe { current_ptr.as_ref()? };
+            let next_ptr = xor_ptrs(node.xor_ptr, prev_ptr);
+            prev_ptr = current_ptr;
+            current_ptr = next_ptr;
+            Some(node)
+        });
+
+        f.debug_list().entries(nodes).finish()
+    }
+}
+
+/// contiguous block of node slots, shared by every list that may own nodes inside of it
+struct ArenaChunk<T> {
+    slots: *mut [MaybeUninit<XorNode<T>>],
+}
+impl<T> ArenaChunk<T> {
+    /// allocates a chunk with the capacity and exposes its provenance for the nodes in it
+    fn allocate(capacity: usize) -> Self {
+        let slots = Box::into_raw(Box::new_uninit_slice(capacity));
+        slots.expose_provenance();
+
+        Self { slots }
+    }
+
+    fn first_slot(&self) -> *mut XorNode<T> {
+        self.slots as *mut XorNode<T>
+    }
+
+    fn contains(&self, node: *mut XorNode<T>) -> bool {
+        let start = self.first_slot().addr();
+        let end = start + self.slots.len() * size_of::<XorNode<T>>();
+        (start..end).contains(&node.addr())
+    }
+}
+impl<T> Drop for ArenaChunk<T> {
+    fn drop(&mut self) {
+        // the payloads have already been moved out of the slots
+        unsafe {
+            drop(Box::from_raw(self.slots));
+        }
+    }
+}
+
+/// chunks sorted by address and their unused slots, shared by every list that may own nodes inside of them
+struct ChunkRegistry<T> {
+    chunks: Vec<Rc<ArenaChunk<T>>>,
+    /// heads of lists of freed slots linked through their XOR pointers, slots are taken from the last one
+    free_lists: Vec<*mut XorNode<T>>,
+    /// ranges of never used slots at the ends of chunks, slots are taken from the last one
+    unused_ranges: Vec<(*mut XorNode<T>, *mut XorNode<T>)>,
+}
+impl<T> ChunkRegistry<T> {
+    fn new(chunk: ArenaChunk<T>) -> Self {
+        let first_slot = chunk.first_slot();
+        let range = (first_slot, first_slot.wrapping_add(chunk.slots.len()));
+
+        Self {
+            chunks: vec![Rc::new(chunk)],
+            free_lists: Vec::new(),
+            unused_ranges: vec![range],
+        }
+    }
+
+    /// returns the number of chunks that start at or before the address
+    fn chunks_before(&self, address: usize) -> usize {
+        self.chunks
+            .partition_point(|chunk| chunk.first_slot().addr() <= address)
+    }
+
+    fn contains(&self, node: *mut XorNode<T>) -> bool {
+        let index = self.chunks_before(node.addr());
+        index > 0 && self.chunks[index - 1].contains(node)
+    }
+
+    fn insert(&mut self, chunk: &Rc<ArenaChunk<T>>) {
+        let index = self.chunks_before(chunk.first_slot().addr());
+        if index == 0 || !Rc::ptr_eq(&self.chunks[index - 1], chunk) {
+            self.chunks.insert(index, chunk.clone());
+        }
+    }
+
+    /// moves the chunks and the unused slots of the other registry into this one
+    fn merge(&mut self, other: &mut Self) {
+        for chunk in &other.chunks {
+            self.insert(chunk);
+        }
+        self.free_lists.append(&mut other.free_lists);
+        self.unused_ranges.append(&mut other.unused_ranges);
+    }
+
+    /// takes a freed or never used slot
+    fn take_slot(&mut self) -> Option<*mut XorNode<T>> {
+        while let Some(head) = self.free_lists.last_mut() {
+            if head.is_null() {
+                self.free_lists.pop();
+                continue;
+            }
+            let node = *head;
+            *head = unsafe { (*node).xor_ptr };
+            return Some(node);
+        }
+        while let Some((next, end)) = self.unused_ranges.last_mut() {
+            if next == end {
+                self.unused_ranges.pop();
+                continue;
+            }
+            let node = *next;
+            *next = node.wrapping_add(1);
+            return Some(node);
+        }
+
+        None
+    }
+
+    /// adds the slot, whose payload has been moved out, to the freed slots
+    unsafe fn free_slot(&mut self, node: *mut XorNode<T>) {
+        match self.free_lists.last_mut() {
+            Some(head) => {
+                unsafe { (*node).xor_ptr = *head };
+                *head = node;
+            }
+            None => {
+                unsafe { (*node).xor_ptr = null_mut() };
+                self.free_lists.push(node);
+            }
+        }
+    }
+}
+
+/// allocator for the nodes of a list, handing out slots of pre-allocated chunks
+/// before falling back to individually boxed nodes
+struct NodeArena<T> {
+    /// chunks that nodes of the list may be in, lists that exchange nodes share one registry
+    /// and with it the free slots of the chunks
+    chunks: Option<Rc<RefCell<ChunkRegistry<T>>>>,
+    /// individually boxed nodes kept for reuse by `clear_retaining`, linked through their XOR pointers
+    retained_ptr: *mut XorNode<T>,
+}
+impl<T> NodeArena<T> {
+    const fn new() -> Self {
+        Self {
+            chunks: None,
+            retained_ptr: null_mut(),
+        }
+    }
+
+    fn with_capacity(capacity: usize) -> Self {
+        if capacity == 0 {
+            return Self::new();
+        }
+
+        Self {
+            chunks: Some(Rc::new(RefCell::new(ChunkRegistry::new(
+                ArenaChunk::allocate(capacity),
+            )))),
+            retained_ptr: null_mut(),
+        }
+    }
+
+    /// returns an arena that shares the chunks and their free slots, so that it can free nodes moved out of this arena,
+    /// sharing only increments the reference count of the registry
+    fn share(&self) -> Self {
+        Self {
+            chunks: self.chunks.clone(),
+            ..Self::new()
+        }
+    }
+
+    /// shares the chunks of the other arena, so that this arena can free nodes moved out of it
+    fn share_from(&mut self, other: &Self) {
+        let Some(other_chunks) = &other.chunks else {
+            return;
+        };
+        let Some(own_chunks) = &self.chunks else {
+            self.chunks = Some(other_chunks.clone());
+            return;
+        };
+        if Rc::ptr_eq(own_chunks, other_chunks) {
+            return;
+        }
+
+        // the smaller registry is merged into the larger one, so repeated merging stays cheap
+        let (smaller, larger) =
+            if own_chunks.borrow().chunks.len() < other_chunks.borrow().chunks.len() {
+                (own_chunks.clone(), other_chunks.clone())
+            } else {
+                (other_chunks.clone(), own_chunks.clone())
+            };
+        larger.borrow_mut().merge(&mut smaller.borrow_mut());
+        self.chunks = Some(larger);
+    }
+
+    fn contains(&self, node: *mut XorNode<T>) -> bool {
+        self.chunks
+            .as_ref()
+            .is_some_and(|chunks| chunks.borrow().contains(node))
+    }
+
+    /// allocates a node for the value, preferring free chunk slots and then retained boxed nodes
+    fn allocate(&mut self, value: T) -> *mut XorNode<T> {
+        let slot = self
+            .chunks
+            .as_ref()
+            .and_then(|chunks| chunks.borrow_mut().take_slot());
+        let node = if let Some(node) = slot {
+            node
+        } else if !self.retained_ptr.is_null() {
+            let node = self.retained_ptr;
+            self.retained_ptr = unsafe { (*node).xor_ptr };
+            node
+        } else {
+            return XorNode::allocate(value);
+        };
+
+        unsafe {
+            node.write(XorNode {
+                payload: value,
+                xor_ptr: null_mut(),
+            });
+        }
+        node
+    }
+
+    /// frees the node and returns its payload, chunk slots are kept for reuse by every list sharing the chunks
+    unsafe fn free(&mut self, node: *mut XorNode<T>) -> T {
+        unsafe {
+            if let Some(chunks) = &self.chunks {
+                let mut chunks = chunks.borrow_mut();
+                if chunks.contains(node) {
+                    let payload = addr_of!((*node).payload).read();
+                    chunks.free_slot(node);
+                    return payload;
+                }
+            }
+            Box::from_raw(node).payload
+        }
+    }
+
+    /// frees the node and returns its payload, keeping boxed nodes for reuse as well if the arena has chunks
+    unsafe fn free_retaining(&mut self, node: *mut XorNode<T>) -> T {
+        unsafe {
+            if self.chunks.is_none() || self.contains(node) {
+                return self.free(node);
+            }
+            let payload = addr_of!((*node).payload).read();
+            (*node).xor_ptr = self.retained_ptr;
+            self.retained_ptr = node;
+            payload
+        }
+    }
+}
+impl<T> Drop for NodeArena<T> {
+    fn drop(&mut self) {
+        // the payloads of retained nodes have already been moved out
+        while !self.retained_ptr.is_null() {
+            unsafe {
+                let node = self.retained_ptr;
+                self.retained_ptr = (*node).xor_ptr;
+                drop(Box::from_raw(node as *mut MaybeUninit<XorNode<T>>));
+            }
+        }
+    }
+}
+
+/// error returned when a range is out of the bounds of a list
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RangeError {
+    /// the requested start index
+    pub start: usize,
+    /// the requested end index (exclusive)
+    pub end: usize,
+    /// the length of the list
+    pub len: usize,
+}
+impl Display for RangeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "range {}..{} is out of bounds for a list of length {}",
+            self.start, self.end, self.len
+        )
+    }
+}
+impl Error for RangeError {}
+
+/// fallible indexing that can be used as a bound in generic code
+pub trait GetExt<T> {
+    /// returns a reference to the element at the index or None if it is out of bounds
+    fn try_index(&self, index: usize) -> Option<&T>;
+}
+impl<T> GetExt<T> for XorLinkedList<T> {
+    fn try_index(&self, index: usize) -> Option<&T> {
+        self.get(index)
+    }
+}
+impl<T> GetExt<T> for [T] {
+    fn try_index(&self, index: usize) -> Option<&T> {
+        self.get(index)
+    }
+}
+impl<T> GetExt<T> for Vec<T> {
+    fn try_index(&self, index: usize) -> Option<&T> {
+        self.as_slice().get(index)
     }
 }
 
@@ -39,6 +389,7 @@ pub struct XorLinkedList<T> {
     size: usize,
     start: *mut XorNode<T>,
     end: *mut XorNode<T>,
+    arena: NodeArena<T>,
 }
 impl<T> XorLinkedList<T> {
     /// creates an empty XOR linked list
@@ -47,10 +398,32 @@ impl<T> XorLinkedList<T> {
             size: 0,
             start: null_mut(),
             end: null_mut(),
+            arena: NodeArena::new(),
+        }
+    }
+
+    /// creates an empty XOR linked list with storage for capacity nodes allocated in a single block,
+    /// nodes beyond the capacity are allocated individually
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            size: 0,
+            start: null_mut(),
+            end: null_mut(),
+            arena: NodeArena::with_capacity(capacity),
         }
     }
 
-    /// removes all elements from the list
+    /// creates an empty list that can own nodes moved out of this list
+    fn new_sharing_arena(&self) -> Self {
+        Self {
+            size: 0,
+            start: null_mut(),
+            end: null_mut(),
+            arena: self.arena.share(),
+        }
+    }
+
+    /// removes all elements from the list, dropping them from the start to the end
     pub fn clear(&mut self) {
         loop {
             if self.pop_front().is_none() {
@@ -59,6 +432,30 @@ impl<T> XorLinkedList<T> {
         }
     }
 
+    /// removes all elements from the list, dropping them from the end to the start
+    pub fn clear_back(&mut self) {
+        loop {
+            if self.pop_back().is_none() {
+                return;
+            }
+        }
+    }
+
+    /// removes all elements from the list, dropping them from the start to the end, and keeps the
+    /// node memory for reuse by later insertions if the list was created with a capacity
+    /// (same as `clear` otherwise)
+    pub fn clear_retaining(&mut self) {
+        loop {
+            unsafe {
+                let old_ptr = Self::unlink_end(&mut self.size, &mut self.start, &mut self.end);
+                if old_ptr.is_null() {
+                    return;
+                }
+                drop(self.arena.free_retaining(old_ptr));
+            }
+        }
+    }
+
     /// returns a reference of the first element if present
     pub fn peek_front(&self) -> Option<&T> {
         if self.size == 0 {
@@ -103,6 +500,26 @@ impl<T> XorLinkedList<T> {
         }
     }
 
+    /// returns a reference of the first element if present, same as `peek_front`
+    pub fn first(&self) -> Option<&T> {
+        self.peek_front()
+    }
+
+    /// returns a mutable reference of the first element if present, same as `peek_front_mut`
+    pub fn first_mut(&mut self) -> Option<&mut T> {
+        self.peek_front_mut()
+    }
+
+    /// returns a reference of the last element if present, same as `peek_back`
+    pub fn last(&self) -> Option<&T> {
+        self.peek_back()
+    }
+
+    /// returns a mutable reference of the last element if present, same as `peek_back_mut`
+    pub fn last_mut(&mut self) -> Option<&mut T> {
+        self.peek_back_mut()
+    }
+
     /// returns a reference the element at the index
     pub fn get(&self, index: usize) -> Option<&T> {
         if index >= self.size {
@@ -125,6 +542,24 @@ impl<T> XorLinkedList<T> {
         Some(unsafe { &mut (*ptr).payload })
     }
 
+    /// returns a reference to the element at the index without checking the bounds
+    ///
+    /// # Safety
+    ///
+    /// the index must be less than the length of the list
+    pub unsafe fn get_unchecked(&self, index: usize) -> &T {
+        unsafe { &(*self.get_ptr_at(index)).payload }
+    }
+
+    /// returns a mutable reference to the element at the index without checking the bounds
+    ///
+    /// # Safety
+    ///
+    /// the index must be less than the length of the list
+    pub unsafe fn get_unchecked_mut(&mut self, index: usize) -> &mut T {
+        unsafe { &mut (*self.get_ptr_at(index)).payload }
+    }
+
     #[inline]
     unsafe fn get_ptr_at(&self, index: usize) -> *mut XorNode<T> {
         debug_assert!(index < self.size);
@@ -157,10 +592,13 @@ impl<T> XorLinkedList<T> {
         self.size == 0
     }
 
+    /// links a detached node (with a null XOR pointer) after end_ptr2, the end of the list it is inserted at
     #[inline]
-    unsafe fn push_end(end_ptr1: &mut *mut XorNode<T>, end_ptr2: &mut *mut XorNode<T>, value: T) {
-        let new_node = XorNode::allocate(value);
-
+    unsafe fn link_end(
+        end_ptr1: &mut *mut XorNode<T>,
+        end_ptr2: &mut *mut XorNode<T>,
+        new_node: *mut XorNode<T>,
+    ) {
         if end_ptr2.is_null() {
             debug_assert!(end_ptr1.is_null());
             *end_ptr1 = new_node;
@@ -178,7 +616,8 @@ impl<T> XorLinkedList<T> {
     pub fn push_back(&mut self, value: T) {
         self.size += 1;
         unsafe {
-            Self::push_end(&mut self.start, &mut self.end, value);
+            let new_node = self.arena.allocate(value);
+            Self::link_end(&mut self.start, &mut self.end, new_node);
         }
     }
 
@@ -186,20 +625,23 @@ impl<T> XorLinkedList<T> {
     pub fn push_front(&mut self, value: T) {
         self.size += 1;
         unsafe {
-            Self::push_end(&mut self.end, &mut self.start, value);
+            let new_node = self.arena.allocate(value);
+            Self::link_end(&mut self.end, &mut self.start, new_node);
         }
     }
 
+    /// detaches the node at end_ptr1 without freeing it and returns it with a null XOR pointer,
+    /// returns null if the list is empty
     #[inline]
-    unsafe fn pop_end(
+    unsafe fn unlink_end(
         size: &mut usize,
         end_ptr1: &mut *mut XorNode<T>,
         end_ptr2: &mut *mut XorNode<T>,
-    ) -> Option<T> {
+    ) -> *mut XorNode<T> {
         if end_ptr1.is_null() {
             debug_assert!(end_ptr2.is_null());
             debug_assert_eq!(0, *size);
-            return None;
+            return null_mut();
         }
         let old_ptr = *end_ptr1;
         unsafe {
@@ -209,25 +651,32 @@ impl<T> XorLinkedList<T> {
             } else {
                 *end_ptr1 = (**end_ptr1).xor_ptr;
                 (**end_ptr1).xor_ptr = xor_ptrs((**end_ptr1).xor_ptr, old_ptr);
+                (*old_ptr).xor_ptr = null_mut();
             }
-
-            *size -= 1;
-            Some(Box::from_raw(old_ptr).payload)
         }
+        *size -= 1;
+
+        old_ptr
     }
 
     /// removes and returns the element from the start of the list
     pub fn pop_front(&mut self) -> Option<T> {
-        unsafe { Self::pop_end(&mut self.size, &mut self.start, &mut self.end) }
+        unsafe {
+            let old_ptr = Self::unlink_end(&mut self.size, &mut self.start, &mut self.end);
+            (!old_ptr.is_null()).then(|| self.arena.free(old_ptr))
+        }
     }
 
     /// removes and returns the element from the end of the list
     pub fn pop_back(&mut self) -> Option<T> {
-        unsafe { Self::pop_end(&mut self.size, &mut self.end, &mut self.start) }
+        unsafe {
+            let old_ptr = Self::unlink_end(&mut self.size, &mut self.end, &mut self.start);
+            (!old_ptr.is_null()).then(|| self.arena.free(old_ptr))
+        }
     }
 
     /// returns an iterator of element references from the start to the end of the list
-    pub fn iter(&self) -> impl Iterator<Item = &T> {
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
         self.into_iter()
     }
 
@@ -244,11 +693,14 @@ impl<T> XorLinkedList<T> {
     }
 
     /// returns an iterator of element references from the end to the start of the list
-    pub fn reverse_iter(&self) -> impl Iterator<Item = &T> {
+    pub fn reverse_iter(&self) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
         RefXorLinkedListIter {
             xor_linked_list_lifetime: PhantomData,
             current_ptr: self.end,
             prev_ptr: null_mut(),
+            back_ptr: self.start,
+            back_next_ptr: null_mut(),
+            remaining: self.size,
         }
     }
 
@@ -262,14 +714,16 @@ impl<T> XorLinkedList<T> {
         }
     }
 
-    /// reverses the order of the list
+    /// reverses the order of the list in O(1), the xor of the neighbours of a node is the same in
+    /// both directions, so swapping the ends is observationally the same as relinking every node
     pub fn reverse(&mut self) {
         swap(&mut self.start, &mut self.end);
     }
 
-    /// returns a tuple of the pointers at index and index-1, where 0 < index < size-1
+    /// returns a tuple of the pointers at index and index-1, where 0 <= index <= size
+    /// (the pointer at index is null when index == size, the previous is null when index == 0)
     #[inline]
-    unsafe fn get_ptr_at_and_prev(&mut self, index: usize) -> (*mut XorNode<T>, *mut XorNode<T>) {
+    unsafe fn get_ptr_at_and_prev(&self, index: usize) -> (*mut XorNode<T>, *mut XorNode<T>) {
         let mut prev_ptr = null_mut();
         let is_backwards_iteration = index > self.size / 2;
         let (mut current_ptr, mut jump_count) = if is_backwards_iteration {
@@ -311,7 +765,7 @@ impl<T> XorLinkedList<T> {
                 (*current_ptr).xor_ptr = xor_ptrs((*current_ptr).xor_ptr, prev_ptr);
                 (*prev_ptr).xor_ptr = xor_ptrs((*prev_ptr).xor_ptr, current_ptr);
 
-                let new_node = XorNode::allocate(value);
+                let new_node = self.arena.allocate(value);
                 (*new_node).xor_ptr = xor_ptrs(current_ptr, prev_ptr);
 
                 (*current_ptr).xor_ptr = xor_ptrs((*current_ptr).xor_ptr, new_node);
@@ -340,728 +794,5204 @@ impl<T> XorLinkedList<T> {
                 (*prev_ptr).xor_ptr = xor_ptrs((*prev_ptr).xor_ptr, next_ptr);
                 self.size -= 1;
 
-                Some(Box::from_raw(current_ptr).payload)
+                Some(self.arena.free(current_ptr))
             }
         }
     }
-}
-impl<T: PartialEq> PartialEq for XorLinkedList<T> {
-    fn eq(&self, other: &Self) -> bool {
-        self.len() == other.len() && self.iter().zip(other.iter()).all(|(a, b)| a == b)
-    }
-}
-impl<T: Eq> Eq for XorLinkedList<T> {}
-impl<T: Hash> Hash for XorLinkedList<T> {
-    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
-        self.len().hash(state);
-        for element in self {
-            element.hash(state);
+
+    /// links a detached node between its neighbours (either of which may be null)
+    #[inline]
+    unsafe fn link(
+        &mut self,
+        prev_ptr: *mut XorNode<T>,
+        new_node: *mut XorNode<T>,
+        next_ptr: *mut XorNode<T>,
+    ) {
+        unsafe {
+            (*new_node).xor_ptr = xor_ptrs(prev_ptr, next_ptr);
+            if prev_ptr.is_null() {
+                self.start = new_node;
+            } else {
+                (*prev_ptr).xor_ptr = xor_ptrs(xor_ptrs((*prev_ptr).xor_ptr, next_ptr), new_node);
+            }
+            if next_ptr.is_null() {
+                self.end = new_node;
+            } else {
+                (*next_ptr).xor_ptr = xor_ptrs(xor_ptrs((*next_ptr).xor_ptr, prev_ptr), new_node);
+            }
         }
+        self.size += 1;
     }
-}
-impl<T> Extend<T> for XorLinkedList<T> {
-    fn extend<A: IntoIterator<Item = T>>(&mut self, iter: A) {
-        for element in iter {
-            self.push_back(element);
+
+    /// unlinks the node between its neighbours (either of which may be null) and returns its value
+    #[inline]
+    unsafe fn unlink(
+        &mut self,
+        prev_ptr: *mut XorNode<T>,
+        current_ptr: *mut XorNode<T>,
+        next_ptr: *mut XorNode<T>,
+    ) -> T {
+        unsafe {
+            if prev_ptr.is_null() {
+                self.start = next_ptr;
+            } else {
+                (*prev_ptr).xor_ptr =
+                    xor_ptrs(xor_ptrs((*prev_ptr).xor_ptr, current_ptr), next_ptr);
+            }
+            if next_ptr.is_null() {
+                self.end = prev_ptr;
+            } else {
+                (*next_ptr).xor_ptr =
+                    xor_ptrs(xor_ptrs((*next_ptr).xor_ptr, current_ptr), prev_ptr);
+            }
+            self.size -= 1;
+
+            self.arena.free(current_ptr)
         }
     }
-}
-impl<T> Index<usize> for XorLinkedList<T> {
-    type Output = T;
 
-    fn index(&self, index: usize) -> &Self::Output {
-        self.get(index).expect(INDEX_BOUNDS_ERROR)
+    /// converts the range to a (start, end) pair of indices, returns an error if it is out of bounds
+    fn try_range_indices(
+        &self,
+        range: impl RangeBounds<usize>,
+    ) -> Result<(usize, usize), RangeError> {
+        let range_start = match range.start_bound() {
+            Bound::Included(&index) => index,
+            Bound::Excluded(&index) => index.saturating_add(1),
+            Bound::Unbounded => 0,
+        };
+        let range_end = match range.end_bound() {
+            Bound::Included(&index) => index.saturating_add(1),
+            Bound::Excluded(&index) => index,
+            Bound::Unbounded => self.size,
+        };
+        if range_start <= range_end && range_end <= self.size {
+            Ok((range_start, range_end))
+        } else {
+            Err(RangeError {
+                start: range_start,
+                end: range_end,
+                len: self.size,
+            })
+        }
     }
-}
-impl<T> IndexMut<usize> for XorLinkedList<T> {
-    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
-        self.get_mut(index).expect(INDEX_BOUNDS_ERROR)
+
+    /// converts the range to a (start, end) pair of indices, panics if it is out of bounds
+    fn range_indices(&self, range: impl RangeBounds<usize>) -> (usize, usize) {
+        self.try_range_indices(range).expect(RANGE_BOUNDS_ERROR)
     }
-}
-impl<T: Clone> Clone for XorLinkedList<T> {
-    fn clone(&self) -> Self {
-        let mut cloned_list = XorLinkedList::new();
-        for element in self {
-            cloned_list.push_back(element.clone());
+
+    /// keeps only the elements in the range for which the predicate returns true,
+    /// elements outside of the range are always kept
+    pub fn retain_range<P: FnMut(&T) -> bool>(
+        &mut self,
+        range: impl RangeBounds<usize>,
+        mut pred: P,
+    ) {
+        let (range_start, range_end) = self.range_indices(range);
+        unsafe {
+            let (mut current_ptr, mut prev_ptr) = self.get_ptr_at_and_prev(range_start);
+            for _ in range_start..range_end {
+                let next_ptr = xor_ptrs((*current_ptr).xor_ptr, prev_ptr);
+                if pred(&(*current_ptr).payload) {
+                    prev_ptr = current_ptr;
+                } else {
+                    self.unlink(prev_ptr, current_ptr, next_ptr);
+                }
+                current_ptr = next_ptr;
+            }
         }
-        cloned_list
-    }
-}
-impl<T> Default for XorLinkedList<T> {
-    fn default() -> Self {
-        Self::new()
     }
-}
-impl<T: Debug> Debug for XorLinkedList<T> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_list().entries(&*self).finish()
+
+    /// returns true if the list contains an element equal to the value
+    pub fn contains(&self, value: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.iter().any(|element| element == value)
     }
-}
-impl<T> Drop for XorLinkedList<T> {
-    fn drop(&mut self) {
-        self.clear();
+
+    /// returns the index of the first element for which the predicate returns true
+    pub fn position<F: FnMut(&T) -> bool>(&self, predicate: F) -> Option<usize> {
+        self.iter().position(predicate)
     }
-}
-impl<T> IntoIterator for XorLinkedList<T> {
-    type Item = T;
 
-    type IntoIter = XorLinkedListIter<T>;
+    /// swaps the elements at the two indices, panics if either index is out of bounds
+    pub fn swap(&mut self, i: usize, j: usize) {
+        assert!(i < self.size && j < self.size, "{}", INDEX_BOUNDS_ERROR);
+        if i == j {
+            return;
+        }
 
-    fn into_iter(self) -> Self::IntoIter {
-        Self::IntoIter {
-            xor_linked_list: self,
+        unsafe {
+            let first_ptr = self.get_ptr_at(i);
+            let second_ptr = self.get_ptr_at(j);
+            swap(&mut (*first_ptr).payload, &mut (*second_ptr).payload);
         }
     }
-}
-impl<'a, T> IntoIterator for &'a XorLinkedList<T> {
-    type Item = &'a T;
-    type IntoIter = RefXorLinkedListIter<'a, T>;
-
-    fn into_iter(self) -> Self::IntoIter {
-        let current_ptr = self.start;
-        let prev_ptr = null_mut();
 
-        RefXorLinkedListIter {
-            xor_linked_list_lifetime: PhantomData,
-            current_ptr,
-            prev_ptr,
+    /// returns the length of the longest sorted prefix, which is the index of the first element
+    /// smaller than its predecessor or the length of the list if it is fully sorted
+    pub fn sorted_prefix_len(&self) -> usize
+    where
+        T: PartialOrd,
+    {
+        let mut iter = self.iter();
+        let Some(mut prev) = iter.next() else {
+            return 0;
+        };
+        for (index, element) in iter.enumerate() {
+            if element < prev {
+                return index + 1;
+            }
+            prev = element;
         }
+
+        self.size
     }
-}
-impl<'a, T> IntoIterator for &'a mut XorLinkedList<T> {
-    type Item = &'a mut T;
-    type IntoIter = MutRefXorLinkedListIter<'a, T>;
 
-    fn into_iter(self) -> Self::IntoIter {
-        let current_ptr = self.start;
-        let prev_ptr = null_mut();
+    /// returns the index, the previous pointer and the pointer of the first node for which the predicate
+    /// returns true, the pointer is null and the index is the size if there is no match
+    #[inline]
+    unsafe fn find_ptr<P: FnMut(&T) -> bool>(
+        &self,
+        mut pred: P,
+    ) -> (usize, *mut XorNode<T>, *mut XorNode<T>) {
+        let mut prev_ptr = null_mut();
+        let mut current_ptr = self.start;
+        let mut index = 0;
+        while !current_ptr.is_null() {
+            unsafe {
+                if pred(&(*current_ptr).payload) {
+                    break;
+                }
+                let next_ptr = xor_ptrs((*current_ptr).xor_ptr, prev_ptr);
+                prev_ptr = current_ptr;
+                current_ptr = next_ptr;
+            }
+            index += 1;
+        }
 
-        MutRefXorLinkedListIter {
-            xor_linked_list_lifetime: PhantomData,
-            current_ptr,
-            prev_ptr,
+        (index, prev_ptr, current_ptr)
+    }
+
+    /// detaches the nodes from index onwards into a new list, where current_ptr is the node at
+    /// the index (null when index == size) and prev_ptr is the node before it
+    unsafe fn split_before(
+        &mut self,
+        index: usize,
+        prev_ptr: *mut XorNode<T>,
+        current_ptr: *mut XorNode<T>,
+    ) -> Self {
+        if current_ptr.is_null() {
+            return Self::new();
+        }
+        let mut tail = self.new_sharing_arena();
+        if prev_ptr.is_null() {
+            tail.append(self);
+            return tail;
+        }
+
+        unsafe {
+            (*prev_ptr).xor_ptr = xor_ptrs((*prev_ptr).xor_ptr, current_ptr);
+            (*current_ptr).xor_ptr = xor_ptrs((*current_ptr).xor_ptr, prev_ptr);
         }
+        tail.size = self.size - index;
+        tail.start = current_ptr;
+        tail.end = self.end;
+        self.size = index;
+        self.end = prev_ptr;
+
+        tail
     }
-}
-impl<T> FromIterator<T> for XorLinkedList<T> {
-    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
-        let mut list = XorLinkedList::new();
-        for element in iter {
-            list.push_back(element);
+
+    /// splits the list before the first element for which the predicate returns true and returns
+    /// the tail starting with that element, returns an empty list if there is no match
+    pub fn split_off_where<P: FnMut(&T) -> bool>(&mut self, pred: P) -> XorLinkedList<T> {
+        unsafe {
+            let (index, prev_ptr, current_ptr) = self.find_ptr(pred);
+            self.split_before(index, prev_ptr, current_ptr)
         }
+    }
 
-        list
+    /// splits the list at the index, returning the elements from the index onwards,
+    /// panics if the index is greater than the size
+    pub fn split_off(&mut self, at: usize) -> XorLinkedList<T> {
+        assert!(
+            at <= self.size,
+            "Index is greater than the size {}",
+            self.size
+        );
+        unsafe {
+            let (current_ptr, prev_ptr) = self.get_ptr_at_and_prev(at);
+            self.split_before(at, prev_ptr, current_ptr)
+        }
     }
-}
-#[cfg(feature = "serde")]
-impl<T: Serialize> Serialize for XorLinkedList<T> {
-    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-        serializer.collect_seq(self.iter())
+
+    /// moves all elements of the other list to the end of this list in O(1), leaving the other list empty
+    pub fn append(&mut self, other: &mut XorLinkedList<T>) {
+        if other.is_empty() {
+            return;
+        }
+
+        self.arena.share_from(&other.arena);
+        if self.is_empty() {
+            self.start = other.start;
+        } else {
+            unsafe {
+                (*self.end).xor_ptr = xor_ptrs((*self.end).xor_ptr, other.start);
+                (*other.start).xor_ptr = xor_ptrs((*other.start).xor_ptr, self.end);
+            }
+        }
+        self.end = other.end;
+        self.size += other.size;
+        other.start = null_mut();
+        other.end = null_mut();
+        other.size = 0;
     }
-}
-#[cfg(feature = "serde")]
-impl<'de, T: Deserialize<'de>> Deserialize<'de> for XorLinkedList<T> {
-    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
-        let vec = Vec::<T>::deserialize(deserializer)?;
-        Ok(vec.into_iter().collect())
+
+    /// rotates the list in place so that the element at mid becomes the first element by relinking
+    /// the ends in O(min(mid, len - mid)), panics if mid is greater than the size
+    pub fn rotate_left(&mut self, mid: usize) {
+        assert!(
+            mid <= self.size,
+            "Index is greater than the size {}",
+            self.size
+        );
+        if mid == 0 || mid == self.size {
+            return;
+        }
+
+        // cut the link before mid and join the old end to the old start, the nodes are not moved
+        unsafe {
+            let (current_ptr, prev_ptr) = self.get_ptr_at_and_prev(mid);
+            (*prev_ptr).xor_ptr = xor_ptrs((*prev_ptr).xor_ptr, current_ptr);
+            (*current_ptr).xor_ptr = xor_ptrs((*current_ptr).xor_ptr, prev_ptr);
+            (*self.end).xor_ptr = xor_ptrs((*self.end).xor_ptr, self.start);
+            (*self.start).xor_ptr = xor_ptrs((*self.start).xor_ptr, self.end);
+            self.start = current_ptr;
+            self.end = prev_ptr;
+        }
     }
-}
 
-pub struct XorLinkedListIter<T> {
-    xor_linked_list: XorLinkedList<T>,
-}
-impl<T> Iterator for XorLinkedListIter<T> {
-    type Item = T;
+    /// rotates the list in place so that the last k elements move to the front,
+    /// panics if k is greater than the size
+    pub fn rotate_right(&mut self, k: usize) {
+        assert!(
+            k <= self.size,
+            "Index is greater than the size {}",
+            self.size
+        );
+        self.rotate_left(self.size - k);
+    }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        self.xor_linked_list.pop_front()
+    /// removes consecutive elements for which the predicate returns true, keeping the first of each run,
+    /// the predicate is passed the current element and the last kept element before it
+    pub fn dedup_by<F: FnMut(&mut T, &mut T) -> bool>(&mut self, mut same_bucket: F) {
+        if self.size < 2 {
+            return;
+        }
+
+        unsafe {
+            let mut kept_ptr = self.start;
+            let mut current_ptr = xor_ptrs((*kept_ptr).xor_ptr, null_mut());
+            while !current_ptr.is_null() {
+                let next_ptr = xor_ptrs((*current_ptr).xor_ptr, kept_ptr);
+                if same_bucket(&mut (*current_ptr).payload, &mut (*kept_ptr).payload) {
+                    self.unlink(kept_ptr, current_ptr, next_ptr);
+                } else {
+                    kept_ptr = current_ptr;
+                }
+                current_ptr = next_ptr;
+            }
+        }
     }
-}
 
-pub struct RefXorLinkedListIter<'a, T> {
-    xor_linked_list_lifetime: PhantomData<&'a XorLinkedList<T>>,
-    current_ptr: *mut XorNode<T>,
-    prev_ptr: *mut XorNode<T>,
-}
-impl<'a, T> Iterator for RefXorLinkedListIter<'a, T> {
-    type Item = &'a T;
+    /// removes consecutive elements that resolve to the same key, keeping the first of each run
+    pub fn dedup_by_key<K: PartialEq, F: FnMut(&mut T) -> K>(&mut self, mut key: F) {
+        self.dedup_by(|current, kept| key(current) == key(kept));
+    }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.current_ptr.is_null() {
-            return None;
+    /// removes consecutive equal elements, keeping the first of each run
+    pub fn dedup(&mut self)
+    where
+        T: PartialEq,
+    {
+        self.dedup_by(|current, kept| current == kept);
+    }
+
+    /// removes consecutive equal elements, keeping the first of each run, by comparing hashes
+    /// first and only checking equality when the hashes match
+    #[cfg(feature = "std")]
+    pub fn dedup_by_hash(&mut self)
+    where
+        T: Hash + PartialEq,
+    {
+        let hash_builder = RandomState::new();
+        let Some(first) = self.peek_front() else {
+            return;
+        };
+        let mut kept_hash = hash_builder.hash_one(first);
+        self.dedup_by(|current, kept| {
+            let current_hash = hash_builder.hash_one(&*current);
+            if current_hash == kept_hash && current == kept {
+                true
+            } else {
+                kept_hash = current_hash;
+                false
+            }
+        });
+    }
+
+    /// clones all elements of the other list to the end of this list, leaving the other list intact
+    pub fn append_cloned(&mut self, other: &XorLinkedList<T>)
+    where
+        T: Clone,
+    {
+        for element in other {
+            self.push_back(element.clone());
         }
+    }
+
+    /// moves the first node of the other list to the end of this list without reallocating it,
+    /// this list has to share the arena of the other list
+    #[inline]
+    unsafe fn move_front_node_from(&mut self, other: &mut XorLinkedList<T>) {
         unsafe {
-            let payload_ref = &(*self.current_ptr).payload;
-            let new_ptr = xor_ptrs((*self.current_ptr).xor_ptr, self.prev_ptr);
-            self.prev_ptr = self.current_ptr;
-            self.current_ptr = new_ptr;
+            let node = Self::unlink_end(&mut other.size, &mut other.start, &mut other.end);
+            debug_assert!(!node.is_null());
+            Self::link_end(&mut self.start, &mut self.end, node);
+        }
+        self.size += 1;
+    }
 
-            Some(payload_ref)
+    /// merges the other list into this one by relinking nodes, assuming both are sorted by the comparator,
+    /// elements of this list come first when they compare equal
+    fn merge_sorted_by<F: FnMut(&T, &T) -> Ordering>(
+        &mut self,
+        mut other: XorLinkedList<T>,
+        compare: &mut F,
+    ) {
+        let mut merged = self.new_sharing_arena();
+        merged.arena.share_from(&other.arena);
+        while let (Some(first), Some(second)) = (self.peek_front(), other.peek_front()) {
+            unsafe {
+                if compare(second, first) == Ordering::Less {
+                    merged.move_front_node_from(&mut other);
+                } else {
+                    merged.move_front_node_from(self);
+                }
+            }
         }
+        merged.append(self);
+        merged.append(&mut other);
+        self.append(&mut merged);
     }
-}
 
-pub struct MutRefXorLinkedListIter<'a, T> {
-    xor_linked_list_lifetime: PhantomData<&'a mut XorLinkedList<T>>,
-    current_ptr: *mut XorNode<T>,
-    prev_ptr: *mut XorNode<T>,
-}
-impl<'a, T> Iterator for MutRefXorLinkedListIter<'a, T> {
-    type Item = &'a mut T;
+    /// sorts the list with a stable bottom-up merge sort using the comparator, relinking nodes
+    /// instead of moving the elements
+    pub fn sort_by<F: FnMut(&T, &T) -> Ordering>(&mut self, mut compare: F) {
+        if self.size < 2 {
+            return;
+        }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.current_ptr.is_null() {
+        // runs[i] is either empty or a sorted run of 2^i elements, higher indices hold earlier elements
+        let mut runs: Vec<XorLinkedList<T>> = Vec::new();
+        while !self.is_empty() {
+            let mut carry = self.new_sharing_arena();
+            unsafe {
+                carry.move_front_node_from(self);
+            }
+            let mut run_index = 0;
+            while run_index < runs.len() && !runs[run_index].is_empty() {
+                let mut run = take(&mut runs[run_index]);
+                run.merge_sorted_by(carry, &mut compare);
+                carry = run;
+                run_index += 1;
+            }
+            if run_index == runs.len() {
+                runs.push(carry);
+            } else {
+                runs[run_index] = carry;
+            }
+        }
+
+        let mut sorted = self.new_sharing_arena();
+        for mut run in runs {
+            run.merge_sorted_by(sorted, &mut compare);
+            sorted = run;
+        }
+        self.append(&mut sorted);
+    }
+
+    /// sorts the list with a stable merge sort
+    pub fn sort(&mut self)
+    where
+        T: Ord,
+    {
+        self.sort_by(T::cmp);
+    }
+
+    /// returns an iterator over the maximal non-decreasing runs of the list,
+    /// a run ends when an element is smaller than its predecessor
+    pub fn monotonic_runs(&self) -> impl Iterator<Item = Vec<&T>>
+    where
+        T: PartialOrd,
+    {
+        let mut iter = self.iter().peekable();
+        from_fn(move || {
+            let mut last = iter.next()?;
+            let mut run = vec![last];
+            while let Some(element) =
+                iter.next_if(|element| (*element).partial_cmp(last) != Some(Ordering::Less))
+            {
+                run.push(element);
+                last = element;
+            }
+
+            Some(run)
+        })
+    }
+
+    /// overwrites the elements from the start index onwards with the values,
+    /// stopping when either the list or the values run out
+    pub fn overwrite_range<I: IntoIterator<Item = T>>(&mut self, start: usize, values: I) {
+        if start >= self.size {
+            return;
+        }
+
+        let mut values = values.into_iter();
+        unsafe {
+            let (mut current_ptr, mut prev_ptr) = self.get_ptr_at_and_prev(start);
+            while !current_ptr.is_null() {
+                let Some(value) = values.next() else {
+                    return;
+                };
+                (*current_ptr).payload = value;
+                let next_ptr = xor_ptrs((*current_ptr).xor_ptr, prev_ptr);
+                prev_ptr = current_ptr;
+                current_ptr = next_ptr;
+            }
+        }
+    }
+
+    /// returns the number of elements for which the predicate is true while it was false for the previous element,
+    /// the first element counts if the predicate is true for it
+    pub fn count_rising_edges<P: FnMut(&T) -> bool>(&self, mut pred: P) -> usize {
+        let mut count = 0;
+        let mut was_matching = false;
+        for element in self {
+            let is_matching = pred(element);
+            if is_matching && !was_matching {
+                count += 1;
+            }
+            was_matching = is_matching;
+        }
+
+        count
+    }
+
+    /// consumes the list and groups the elements by key, keeping the order of the elements within each group
+    #[cfg(feature = "std")]
+    pub fn into_grouped<K: Eq + Hash, F: FnMut(&T) -> K>(self, mut key: F) -> HashMap<K, Vec<T>> {
+        let mut groups: HashMap<K, Vec<T>> = HashMap::new();
+        for element in self {
+            groups.entry(key(&element)).or_default().push(element);
+        }
+
+        groups
+    }
+
+    /// removes and returns the first element if the predicate returns true for it
+    pub fn pop_front_if<P: FnOnce(&T) -> bool>(&mut self, pred: P) -> Option<T> {
+        if pred(self.peek_front()?) {
+            self.pop_front()
+        } else {
+            None
+        }
+    }
+
+    /// removes and returns the last element if the predicate returns true for it
+    pub fn pop_back_if<P: FnOnce(&T) -> bool>(&mut self, pred: P) -> Option<T> {
+        if pred(self.peek_back()?) {
+            self.pop_back()
+        } else {
+            None
+        }
+    }
+
+    /// inserts all values into the sorted list, keeping it sorted, by sorting the values and
+    /// merging them into the list in a single pass
+    pub fn insert_sorted_many<I: IntoIterator<Item = T>>(&mut self, values: I)
+    where
+        T: Ord,
+    {
+        let mut values = XorLinkedList::from_iter(values);
+        values.sort();
+        self.merge_sorted_by(values, &mut T::cmp);
+    }
+
+    /// consumes the list and returns a vector of its elements in order
+    pub fn into_vec(self) -> Vec<T> {
+        self.into()
+    }
+
+    /// returns a vector of clones of the elements in order
+    pub fn to_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        let mut vec = Vec::with_capacity(self.size);
+        vec.extend(self.iter().cloned());
+
+        vec
+    }
+
+    /// returns a vector of clones of at most max elements from the start of the list
+    pub fn collect_capped(&self, max: usize) -> Vec<T>
+    where
+        T: Clone,
+    {
+        let mut vec = Vec::with_capacity(max.min(self.size));
+        vec.extend(self.iter().take(max).cloned());
+
+        vec
+    }
+
+    /// removes the first element equal to the value, returns true if an element was removed
+    pub fn remove_first(&mut self, value: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        unsafe {
+            let (_, prev_ptr, current_ptr) = self.find_ptr(|element| element == value);
+            if current_ptr.is_null() {
+                return false;
+            }
+            let next_ptr = xor_ptrs((*current_ptr).xor_ptr, prev_ptr);
+            self.unlink(prev_ptr, current_ptr, next_ptr);
+        }
+
+        true
+    }
+
+    /// swaps the elements at index and index + 1, returns false if index + 1 is out of bounds
+    pub fn swap_adjacent(&mut self, index: usize) -> bool {
+        if index.saturating_add(1) >= self.size {
+            return false;
+        }
+
+        unsafe {
+            let (next_ptr, current_ptr) = self.get_ptr_at_and_prev(index + 1);
+            swap(&mut (*current_ptr).payload, &mut (*next_ptr).payload);
+        }
+
+        true
+    }
+
+    /// returns the indices of all elements for which the predicate returns true
+    pub fn positions<P: FnMut(&T) -> bool>(&self, mut pred: P) -> Vec<usize> {
+        self.iter()
+            .enumerate()
+            .filter(|(_, element)| pred(element))
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    /// inserts the value before the first element greater than it according to the comparator,
+    /// keeping a sorted list sorted, and returns the index it was inserted at
+    pub fn insert_sorted_by<F: FnMut(&T, &T) -> Ordering>(
+        &mut self,
+        value: T,
+        mut compare: F,
+    ) -> usize {
+        unsafe {
+            let (index, prev_ptr, current_ptr) =
+                self.find_ptr(|element| compare(element, &value) == Ordering::Greater);
+            let new_node = self.arena.allocate(value);
+            self.link(prev_ptr, new_node, current_ptr);
+
+            index
+        }
+    }
+
+    /// inserts the value before the first element greater than it, keeping a sorted list sorted,
+    /// and returns the index it was inserted at
+    pub fn insert_sorted(&mut self, value: T) -> usize
+    where
+        T: Ord,
+    {
+        self.insert_sorted_by(value, T::cmp)
+    }
+
+    /// returns mutable references to the elements at two distinct indices,
+    /// returns None if the indices are equal or either is out of bounds
+    pub fn get_pair_mut(&mut self, i: usize, j: usize) -> Option<(&mut T, &mut T)> {
+        if i == j || i >= self.size || j >= self.size {
             return None;
         }
+
         unsafe {
-            let payload_ref = &mut (*self.current_ptr).payload;
-            let new_ptr = xor_ptrs((*self.current_ptr).xor_ptr, self.prev_ptr);
-            self.prev_ptr = self.current_ptr;
-            self.current_ptr = new_ptr;
+            let first_ptr = self.get_ptr_at(i);
+            let second_ptr = self.get_ptr_at(j);
+            Some((&mut (*first_ptr).payload, &mut (*second_ptr).payload))
+        }
+    }
+
+    /// returns an iterator of element references starting at the index, wrapping around to the start
+    /// of the list after the end, panics if the index is greater than the size
+    pub fn iter_rotated(&self, start: usize) -> impl Iterator<Item = &T> {
+        assert!(
+            start <= self.size,
+            "Index is greater than the size {}",
+            self.size
+        );
+        self.iter_from(start).chain(self.iter().take(start))
+    }
+
+    /// folds all elements in rotated order starting at the index, wrapping around to the start
+    /// of the list after the end, panics if the index is greater than the size
+    pub fn fold_rotated<B, F: FnMut(B, &T) -> B>(&self, start: usize, init: B, f: F) -> B {
+        self.iter_rotated(start).fold(init, f)
+    }
+
+    /// returns references to the elements in the range, or an error if the range is out of bounds
+    pub fn try_get_range(&self, range: impl RangeBounds<usize>) -> Result<Vec<&T>, RangeError> {
+        let (range_start, range_end) = self.try_range_indices(range)?;
+
+        Ok(self
+            .iter_from(range_start)
+            .take(range_end - range_start)
+            .collect())
+    }
+
+    /// moves all elements of the other list to the start of this list in O(1), keeping their order
+    /// and leaving the other list empty
+    pub fn prepend(&mut self, other: &mut XorLinkedList<T>) {
+        if other.is_empty() {
+            return;
+        }
+
+        self.arena.share_from(&other.arena);
+        if self.is_empty() {
+            self.end = other.end;
+        } else {
+            unsafe {
+                (*self.start).xor_ptr = xor_ptrs((*self.start).xor_ptr, other.end);
+                (*other.end).xor_ptr = xor_ptrs((*other.end).xor_ptr, self.start);
+            }
+        }
+        self.start = other.start;
+        self.size += other.size;
+        other.start = null_mut();
+        other.end = null_mut();
+        other.size = 0;
+    }
+
+    /// merges the other sorted list into this sorted list by relinking nodes, dropping elements equal
+    /// to their predecessor, so the result is the sorted union of both lists
+    pub fn merge_dedup(&mut self, other: XorLinkedList<T>)
+    where
+        T: Ord,
+    {
+        self.merge_sorted_by(other, &mut T::cmp);
+        self.dedup();
+    }
+
+    /// consumes the list and returns a vector of its elements from the end to the start
+    pub fn into_vec_reversed(self) -> Vec<T> {
+        let mut vec = Vec::with_capacity(self.size);
+        vec.extend(self.into_reverse_iter());
+
+        vec
+    }
+
+    /// keeps only the elements for which the predicate returns true and that are not equal to the
+    /// previously kept element, in a single pass, so the dedup applies to the kept sequence
+    /// (keeping odd numbers of `[1, 2, 1]` results in `[1]`)
+    pub fn retain_dedup<F: FnMut(&T) -> bool>(&mut self, mut keep: F)
+    where
+        T: PartialEq,
+    {
+        let mut prev_ptr = null_mut();
+        let mut current_ptr = self.start;
+        while !current_ptr.is_null() {
+            unsafe {
+                let next_ptr = xor_ptrs((*current_ptr).xor_ptr, prev_ptr);
+                let payload = &(*current_ptr).payload;
+                if keep(payload) && (prev_ptr.is_null() || *payload != (*prev_ptr).payload) {
+                    prev_ptr = current_ptr;
+                } else {
+                    self.unlink(prev_ptr, current_ptr, next_ptr);
+                }
+                current_ptr = next_ptr;
+            }
+        }
+    }
+
+    /// returns the number of adjacent pairs of elements for which the predicate returns true
+    pub fn count_pairs<P: FnMut(&T, &T) -> bool>(&self, mut pred: P) -> usize {
+        self.iter()
+            .zip(self.iter().skip(1))
+            .filter(|(first, second)| pred(first, second))
+            .count()
+    }
+
+    /// returns the elements formatted with Display and joined by the separator
+    pub fn join(&self, sep: &str) -> String
+    where
+        T: Display,
+    {
+        let mut joined = String::new();
+        for (index, element) in self.iter().enumerate() {
+            if index > 0 {
+                joined.push_str(sep);
+            }
+            write!(joined, "{element}").expect("Writing to a String does not fail");
+        }
+
+        joined
+    }
+
+    /// shortens the list to the length, dropping the rest of the elements,
+    /// does nothing if the length is greater than or equal to the size
+    pub fn truncate(&mut self, len: usize) {
+        if len < self.size {
+            drop(self.split_off(len));
+        }
+    }
+
+    /// resizes the list to the new length, pushing values generated by the closure to the end
+    /// when growing and truncating when shrinking
+    pub fn resize_with<F: FnMut() -> T>(&mut self, new_len: usize, mut f: F) {
+        self.truncate(new_len);
+        while self.size < new_len {
+            self.push_back(f());
+        }
+    }
+
+    /// resizes the list to the new length, pushing clones of the value to the end
+    /// when growing and truncating when shrinking
+    pub fn resize(&mut self, new_len: usize, value: T)
+    where
+        T: Clone,
+    {
+        self.resize_with(new_len, || value.clone());
+    }
+
+    /// consumes the list and returns a consumer that can peek at and push back values
+    pub fn into_peekable_consumer(self) -> PeekableConsumer<T> {
+        PeekableConsumer {
+            xor_linked_list: self,
+        }
+    }
+
+    /// splits the list at size / 2 and returns mutable iterators over the two halves, both moving inward:
+    /// the first from the start to the middle and the second from the end to the middle
+    /// (the middle element of an odd sized list belongs to the second half)
+    pub fn halves_mut(&mut self) -> (impl Iterator<Item = &mut T>, impl Iterator<Item = &mut T>) {
+        let mid = self.size / 2;
+        let first_half = MutRefXorLinkedListIter {
+            xor_linked_list_lifetime: PhantomData,
+            current_ptr: self.start,
+            prev_ptr: null_mut(),
+        };
+        let second_half = MutRefXorLinkedListIter {
+            xor_linked_list_lifetime: PhantomData,
+            current_ptr: self.end,
+            prev_ptr: null_mut(),
+        };
+
+        (first_half.take(mid), second_half.take(self.size - mid))
+    }
+
+    /// returns an iterator that removes and yields the elements for which the filter returns true,
+    /// elements that are not yielded remain in the list
+    pub fn extract_if<F: FnMut(&mut T) -> bool>(&mut self, filter: F) -> ExtractIf<'_, T, F> {
+        ExtractIf {
+            current_ptr: self.start,
+            prev_ptr: null_mut(),
+            xor_linked_list: self,
+            filter,
+        }
+    }
+
+    /// swaps the elements at the two indices, returns false without swapping if either index is out of bounds
+    pub fn checked_swap(&mut self, i: usize, j: usize) -> bool {
+        if i >= self.size || j >= self.size {
+            return false;
+        }
+        self.swap(i, j);
+
+        true
+    }
+
+    /// removes the leading elements for which the predicate returns true and returns how many were removed
+    pub fn drain_count_while<P: FnMut(&T) -> bool>(&mut self, mut pred: P) -> usize {
+        let mut count = 0;
+        while self.pop_front_if(&mut pred).is_some() {
+            count += 1;
+        }
+
+        count
+    }
+
+    /// returns an iterator of element references from the index to the end of the list,
+    /// the iterator is empty if the index is out of bounds
+    pub fn iter_from(
+        &self,
+        index: usize,
+    ) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        let (current_ptr, prev_ptr) = if index < self.size {
+            unsafe { self.get_ptr_at_and_prev(index) }
+        } else {
+            (null_mut(), null_mut())
+        };
+
+        RefXorLinkedListIter {
+            xor_linked_list_lifetime: PhantomData,
+            current_ptr,
+            prev_ptr,
+            back_ptr: self.end,
+            back_next_ptr: null_mut(),
+            remaining: self.size.saturating_sub(index),
+        }
+    }
+
+    /// returns an iterator of mutable element references from the index to the end of the list,
+    /// the iterator is empty if the index is out of bounds
+    pub fn iter_from_mut(&mut self, index: usize) -> impl Iterator<Item = &mut T> {
+        let (current_ptr, prev_ptr) = if index < self.size {
+            unsafe { self.get_ptr_at_and_prev(index) }
+        } else {
+            (null_mut(), null_mut())
+        };
+
+        MutRefXorLinkedListIter {
+            xor_linked_list_lifetime: PhantomData,
+            current_ptr,
+            prev_ptr,
+        }
+    }
+
+    /// swaps the first and the last element in O(1), does nothing if the list has less than 2 elements
+    pub fn swap_ends(&mut self) {
+        if self.size < 2 {
+            return;
+        }
+
+        unsafe {
+            swap(&mut (*self.start).payload, &mut (*self.end).payload);
+        }
+    }
+
+    /// returns the element at the fractional position (0.0 is the first and 1.0 is the last element), out of range fractions are clamped,
+    /// returns None if the list is empty or the fraction is NaN
+    pub fn at_fraction(&self, fraction: f64) -> Option<&T> {
+        if self.size == 0 || fraction.is_nan() {
+            return None;
+        }
+
+        // f64::round is not available in core, the value is non-negative so adding 0.5 and truncating rounds it
+        let index = (fraction.clamp(0.0, 1.0) * (self.size - 1) as f64 + 0.5) as usize;
+
+        self.get(index.min(self.size - 1))
+    }
+
+    /// removes all elements equal to an earlier element, keeping the first occurrence of each value
+    #[cfg(feature = "std")]
+    pub fn unique(&mut self)
+    where
+        T: Eq + Hash,
+    {
+        self.unique_with_hint(0);
+    }
+
+    /// same as `unique`, but pre-sizes the set of seen values for the expected number of unique elements
+    #[cfg(feature = "std")]
+    pub fn unique_with_hint(&mut self, expected_unique: usize)
+    where
+        T: Eq + Hash,
+    {
+        let mut seen = HashSet::with_capacity(expected_unique);
+        let mut prev_ptr = null_mut();
+        let mut current_ptr = self.start;
+        while !current_ptr.is_null() {
+            unsafe {
+                let next_ptr = xor_ptrs((*current_ptr).xor_ptr, prev_ptr);
+                // kept nodes are not freed while the set is alive
+                if seen.insert(&(*current_ptr).payload) {
+                    prev_ptr = current_ptr;
+                } else {
+                    self.unlink(prev_ptr, current_ptr, next_ptr);
+                }
+                current_ptr = next_ptr;
+            }
+        }
+    }
+
+    /// creates a list from the iterator, returns Err(max_len) and drops the already built part
+    /// if the iterator yields more than max_len elements
+    pub fn try_from_iter_bounded<I: IntoIterator<Item = T>>(
+        iter: I,
+        max_len: usize,
+    ) -> Result<Self, usize> {
+        let mut list = XorLinkedList::new();
+        for element in iter {
+            if list.size == max_len {
+                return Err(max_len);
+            }
+            list.push_back(element);
+        }
+
+        Ok(list)
+    }
+
+    /// consumes the list and returns a new list with the elements in reversed order, transformed by the function
+    pub fn reverse_map<F: FnMut(T) -> T>(mut self, mut f: F) -> XorLinkedList<T> {
+        let mut list = XorLinkedList::new();
+        while let Some(element) = self.pop_back() {
+            list.push_back(f(element));
+        }
+
+        list
+    }
+
+    /// splits the list into consecutive lists of n elements (the last one can be shorter) by moving
+    /// the nodes, panics if n is 0
+    pub fn split_every(self, n: usize) -> Vec<XorLinkedList<T>> {
+        self.into_chunks(n).collect()
+    }
+
+    /// merges the other sorted list into this sorted list by relinking nodes, elements of this list
+    /// come first when they are equal
+    pub fn merge(&mut self, other: XorLinkedList<T>)
+    where
+        T: Ord,
+    {
+        self.merge_sorted_by(other, &mut T::cmp);
+    }
+
+    /// merges the other list into this list by relinking nodes, assuming both are sorted by the comparator,
+    /// elements of this list come first when they compare equal
+    pub fn merge_by<F: FnMut(&T, &T) -> Ordering>(
+        &mut self,
+        other: XorLinkedList<T>,
+        mut compare: F,
+    ) {
+        self.merge_sorted_by(other, &mut compare);
+    }
+
+    /// calls the function on every adjacent pair from the start to the end, mutating the left element
+    /// based on the right one, both elements still hold their original values when a pair is visited
+    pub fn pairwise_in_place<F: FnMut(&mut T, &T)>(&mut self, mut f: F) {
+        if self.size < 2 {
+            return;
+        }
+
+        let mut prev_ptr = null_mut();
+        let mut current_ptr = self.start;
+        unsafe {
+            let mut next_ptr = xor_ptrs((*current_ptr).xor_ptr, prev_ptr);
+            while !next_ptr.is_null() {
+                f(&mut (*current_ptr).payload, &(*next_ptr).payload);
+                prev_ptr = current_ptr;
+                current_ptr = next_ptr;
+                next_ptr = xor_ptrs((*current_ptr).xor_ptr, prev_ptr);
+            }
+        }
+    }
+
+    /// consumes the list and returns an iterator of consecutive lists of the size (the last one can be
+    /// shorter), built by moving the nodes, panics if the size is 0
+    pub fn into_chunks(mut self, size: usize) -> impl Iterator<Item = XorLinkedList<T>> {
+        assert!(size > 0, "Chunk size must be greater than 0");
+        from_fn(move || {
+            if self.is_empty() {
+                return None;
+            }
+            let rest = self.split_off(size.min(self.size));
+
+            Some(replace(&mut self, rest))
+        })
+    }
+
+    /// returns a reference to the largest element, the last one if several are equally large
+    /// (named max_element since `max` would be shadowed by `Ord::max` of the list)
+    pub fn max_element(&self) -> Option<&T>
+    where
+        T: Ord,
+    {
+        self.iter().max()
+    }
+
+    /// returns a reference to the smallest element, the first one if several are equally small
+    pub fn min_element(&self) -> Option<&T>
+    where
+        T: Ord,
+    {
+        self.iter().min()
+    }
+
+    /// returns the sum of all elements
+    pub fn sum<'a, S: Sum<&'a T>>(&'a self) -> S {
+        self.iter().sum()
+    }
+
+    /// returns the number of elements in the inclusive range from lo to hi, the list must be sorted
+    /// in ascending order since the walk stops at the first element greater than hi
+    pub fn count_in_range(&self, lo: &T, hi: &T) -> usize
+    where
+        T: Ord,
+    {
+        self.iter()
+            .take_while(|element| *element <= hi)
+            .filter(|element| *element >= lo)
+            .count()
+    }
+
+    /// consumes the list and moves its nodes into lists of exactly n elements, returning them with
+    /// a list of the remaining len % n elements, panics if n is 0
+    pub fn into_chunks_exact(mut self, n: usize) -> (Vec<XorLinkedList<T>>, XorLinkedList<T>) {
+        assert!(n > 0, "Chunk size must be greater than 0");
+        let remainder = self.split_off(self.size - self.size % n);
+
+        (self.into_chunks(n).collect(), remainder)
+    }
+
+    /// consumes the list and splits it by moving nodes before every element for which the predicate
+    /// returns true, so each matching element starts a new list (a match at the start does not create
+    /// an empty list), the predicate is called exactly once for every element in order
+    pub fn split_when<F: FnMut(&T) -> bool>(mut self, mut pred: F) -> Vec<XorLinkedList<T>> {
+        let mut segments = Vec::new();
+        let mut segment = self.new_sharing_arena();
+        while let Some(element) = self.peek_front() {
+            if pred(element) && !segment.is_empty() {
+                segments.push(replace(&mut segment, self.new_sharing_arena()));
+            }
+            unsafe {
+                segment.move_front_node_from(&mut self);
+            }
+        }
+        if !segment.is_empty() {
+            segments.push(segment);
+        }
+
+        segments
+    }
+
+    /// keeps only the elements whose index is in the set
+    #[cfg(feature = "std")]
+    pub fn retain_indices(&mut self, indices: &HashSet<usize>) {
+        let mut index = 0;
+        self.retain_range(.., |_| {
+            let keep = indices.contains(&index);
+            index += 1;
+            keep
+        });
+    }
+
+    /// returns the index of the first element equal to the value
+    pub fn first_index_of(&self, value: &T) -> Option<usize>
+    where
+        T: PartialEq,
+    {
+        self.position(|element| element == value)
+    }
+
+    /// returns the index of the last element equal to the value, searching from the end
+    pub fn last_index_of(&self, value: &T) -> Option<usize>
+    where
+        T: PartialEq,
+    {
+        self.reverse_iter()
+            .position(|element| element == value)
+            .map(|offset| self.size - offset - 1)
+    }
+
+    /// returns an iterator of (index, previous element, element, next element) for every element,
+    /// the previous of the first and the next of the last element are None
+    pub fn context_windows(&self) -> impl Iterator<Item = (usize, Option<&T>, &T, Option<&T>)> {
+        let prevs = once(None).chain(self.iter().map(Some));
+        let nexts = self.iter().skip(1).map(Some).chain(once(None));
+        self.iter()
+            .enumerate()
+            .zip(prevs)
+            .zip(nexts)
+            .map(|(((index, element), prev), next)| (index, prev, element, next))
+    }
+
+    /// removes and returns the first element for which the predicate returns true, replacing it with
+    /// the last element in O(1) after the search, so the order of the list is not preserved
+    pub fn swap_remove_first<P: FnMut(&T) -> bool>(&mut self, pred: P) -> Option<T> {
+        let (_, _, current_ptr) = unsafe { self.find_ptr(pred) };
+        if current_ptr.is_null() {
+            return None;
+        }
+        if current_ptr != self.end {
+            unsafe {
+                swap(&mut (*current_ptr).payload, &mut (*self.end).payload);
+            }
+        }
+
+        self.pop_back()
+    }
+
+    /// consumes both lists and returns a list of the function applied to the paired elements,
+    /// stopping at the end of the shorter list and dropping the rest of the longer one
+    pub fn zip_with<U, R, F>(mut self, mut other: XorLinkedList<U>, mut f: F) -> XorLinkedList<R>
+    where
+        F: FnMut(T, U) -> R,
+    {
+        let mut list = XorLinkedList::new();
+        while let (Some(first), Some(second)) = (self.pop_front(), other.pop_front()) {
+            list.push_back(f(first, second));
+        }
+
+        list
+    }
+
+    /// consumes the list and returns a list of the function applied to every element in order,
+    /// if the function panics the remaining elements are dropped with the list
+    pub fn map<U, F: FnMut(T) -> U>(mut self, mut f: F) -> XorLinkedList<U> {
+        let mut list = XorLinkedList::new();
+        while let Some(element) = self.pop_front() {
+            list.push_back(f(element));
+        }
+
+        list
+    }
+
+    /// keeps one element per distinct value, the first element of the longest run of consecutive equal
+    /// elements of that value (the earliest of equally long runs), and removes all other elements,
+    /// the kept elements stay in their original order
+    #[cfg(feature = "std")]
+    pub fn keep_longest_run(&mut self)
+    where
+        T: Eq + Hash + Clone,
+    {
+        // maps every value to the length and start index of its longest run
+        let mut longest_runs: HashMap<T, (usize, usize)> = HashMap::new();
+        {
+            let mut run_start = 0;
+            let mut iter = self.iter().enumerate().peekable();
+            while let Some((index, element)) = iter.next() {
+                if iter.peek().is_some_and(|(_, next)| *next == element) {
+                    continue;
+                }
+                let run_len = index + 1 - run_start;
+                let longest_run = longest_runs
+                    .entry(element.clone())
+                    .or_insert((run_len, run_start));
+                if run_len > longest_run.0 {
+                    *longest_run = (run_len, run_start);
+                }
+                run_start = index + 1;
+            }
+        }
+
+        let mut index = 0;
+        self.retain_range(.., |element| {
+            let keep = longest_runs[element].1 == index;
+            index += 1;
+            keep
+        });
+    }
+
+    /// clones elements from the start into the slice until either is exhausted and returns the number
+    /// of elements written
+    pub fn copy_into_slice(&self, out: &mut [T]) -> usize
+    where
+        T: Clone,
+    {
+        let written = out.len().min(self.size);
+        for (slot, element) in out.iter_mut().zip(self.iter()) {
+            slot.clone_from(element);
+        }
+
+        written
+    }
+
+    /// walks the list in both directions and panics if the XOR links are inconsistent with the start,
+    /// the end or the size, only available with debug assertions
+    #[cfg(any(debug_assertions, test))]
+    pub fn validate(&self) {
+        let mut forward = Vec::with_capacity(self.size);
+        let mut prev_ptr = null_mut();
+        let mut current_ptr = self.start;
+        while !current_ptr.is_null() {
+            assert!(
+                forward.len() < self.size,
+                "forward walk visits more nodes than the size {}",
+                self.size
+            );
+            forward.push(current_ptr);
+            let next_ptr = unsafe { xor_ptrs((*current_ptr).xor_ptr, prev_ptr) };
+            prev_ptr = current_ptr;
+            current_ptr = next_ptr;
+        }
+        assert_eq!(
+            self.size,
+            forward.len(),
+            "forward walk visits {} nodes but the size is {}",
+            forward.len(),
+            self.size
+        );
+        assert_eq!(
+            self.end, prev_ptr,
+            "forward walk does not finish at the end node"
+        );
+
+        let mut next_ptr = null_mut();
+        let mut current_ptr = self.end;
+        for (index, expected_ptr) in forward.iter().enumerate().rev() {
+            assert_eq!(
+                *expected_ptr, current_ptr,
+                "backward walk reaches a different node at index {}",
+                index
+            );
+            let prev_ptr = unsafe { xor_ptrs((*current_ptr).xor_ptr, next_ptr) };
+            next_ptr = current_ptr;
+            current_ptr = prev_ptr;
+        }
+        assert!(
+            current_ptr.is_null(),
+            "backward walk continues past the start node"
+        );
+    }
+
+    /// consumes the lists and moves all of their nodes into one list in order
+    pub fn concat<I: IntoIterator<Item = XorLinkedList<T>>>(lists: I) -> XorLinkedList<T> {
+        let mut concatenated = XorLinkedList::new();
+        for mut list in lists {
+            concatenated.append(&mut list);
+        }
+
+        concatenated
+    }
+
+    /// calls the function with consecutive batches of up to batch_size element references, reusing
+    /// one buffer, the last batch can be shorter, panics if batch_size is 0
+    pub fn for_each_batch<F: FnMut(&[&T])>(&self, batch_size: usize, mut f: F) {
+        assert!(batch_size > 0, "Batch size must be greater than 0");
+        let mut batch = Vec::with_capacity(batch_size.min(self.size));
+        for element in self {
+            batch.push(element);
+            if batch.len() == batch_size {
+                f(&batch);
+                batch.clear();
+            }
+        }
+        if !batch.is_empty() {
+            f(&batch);
+        }
+    }
+
+    /// rotates the list left by n and returns a clone of the element that was first before the
+    /// rotation, panics if n is greater than the size
+    pub fn rotate_left_returning_old_front(&mut self, n: usize) -> Option<T>
+    where
+        T: Clone,
+    {
+        let old_front = self.peek_front().cloned();
+        self.rotate_left(n);
+
+        old_front
+    }
+
+    /// removes and returns the element at the index, replacing it with the last element,
+    /// so the order of the list is not preserved
+    pub fn swap_remove(&mut self, index: usize) -> Option<T> {
+        if index >= self.size {
+            return None;
+        }
+        unsafe {
+            let ptr = self.get_ptr_at(index);
+            if ptr != self.end {
+                swap(&mut (*ptr).payload, &mut (*self.end).payload);
+            }
+        }
+
+        self.pop_back()
+    }
+
+    /// walks both sorted lists at once and returns clones of the elements that are in only one list
+    /// if keep_unique is true and of the elements that are in both lists if keep_common is true
+    fn select_sorted(&self, other: &Self, keep_unique: bool, keep_common: bool) -> XorLinkedList<T>
+    where
+        T: Ord + Clone,
+    {
+        let mut selected = XorLinkedList::new();
+        let mut first_iter = self.iter().peekable();
+        let mut second_iter = other.iter().peekable();
+        loop {
+            let (next, is_common) = match (first_iter.peek(), second_iter.peek()) {
+                (Some(first), Some(second)) => match first.cmp(second) {
+                    Ordering::Less => (first_iter.next(), false),
+                    Ordering::Greater => (second_iter.next(), false),
+                    Ordering::Equal => {
+                        second_iter.next();
+                        (first_iter.next(), true)
+                    }
+                },
+                (Some(_), None) => (first_iter.next(), false),
+                (None, Some(_)) => (second_iter.next(), false),
+                (None, None) => break,
+            };
+            if (is_common && keep_common) || (!is_common && keep_unique) {
+                selected.extend(next.cloned());
+            }
+        }
+
+        selected
+    }
+
+    /// returns a sorted list of clones of the elements that are in exactly one of the lists,
+    /// both lists must be sorted in ascending order and have no duplicates
+    pub fn symmetric_difference_sorted(&self, other: &Self) -> XorLinkedList<T>
+    where
+        T: Ord + Clone,
+    {
+        self.select_sorted(other, true, false)
+    }
+
+    /// returns a sorted list of clones of the elements that are in both lists,
+    /// both lists must be sorted in ascending order and have no duplicates
+    pub fn intersection_sorted(&self, other: &Self) -> XorLinkedList<T>
+    where
+        T: Ord + Clone,
+    {
+        self.select_sorted(other, false, true)
+    }
+
+    /// returns a sorted list of clones of the distinct elements of both lists,
+    /// both lists must be sorted in ascending order and have no duplicates
+    pub fn union_sorted(&self, other: &Self) -> XorLinkedList<T>
+    where
+        T: Ord + Clone,
+    {
+        self.select_sorted(other, true, true)
+    }
+
+    /// consumes the list and moves its nodes into a list of the elements for which the predicate
+    /// returns true and a list of the rest, keeping their relative order
+    pub fn partition<F: FnMut(&T) -> bool>(
+        mut self,
+        mut pred: F,
+    ) -> (XorLinkedList<T>, XorLinkedList<T>) {
+        let mut matching = self.new_sharing_arena();
+        let mut rest = self.new_sharing_arena();
+        while let Some(element) = self.peek_front() {
+            unsafe {
+                if pred(element) {
+                    matching.move_front_node_from(&mut self);
+                } else {
+                    rest.move_front_node_from(&mut self);
+                }
+            }
+        }
+
+        (matching, rest)
+    }
+
+    /// returns true if the predicate returns true for any element, false for an empty list
+    pub fn any<F: FnMut(&T) -> bool>(&self, f: F) -> bool {
+        self.iter().any(f)
+    }
+
+    /// returns true if the predicate returns true for all elements, true for an empty list
+    pub fn all<F: FnMut(&T) -> bool>(&self, f: F) -> bool {
+        self.iter().all(f)
+    }
+
+    /// returns a reference to the first element for which the predicate returns true
+    pub fn find<F: FnMut(&T) -> bool>(&self, mut f: F) -> Option<&T> {
+        self.iter().find(|element| f(element))
+    }
+
+    /// removes up to max elements from the start of the list and returns them in a vector
+    pub fn drain_front_to_vec(&mut self, max: usize) -> Vec<T> {
+        let count = max.min(self.size);
+        let mut vec = Vec::with_capacity(count);
+        for _ in 0..count {
+            vec.extend(self.pop_front());
+        }
+
+        vec
+    }
+
+    /// returns a list of the function applied to every window of consecutive element references,
+    /// the list is empty if the window is larger than the list, panics if the window is 0
+    pub fn window_reduce<U, F: FnMut(&[&T]) -> U>(
+        &self,
+        window: usize,
+        mut f: F,
+    ) -> XorLinkedList<U> {
+        assert!(window > 0, "Window size must be greater than 0");
+        let mut reduced = XorLinkedList::new();
+        if window > self.size {
+            return reduced;
+        }
+        // the buffer holds up to two windows, when it is full the last window - 1 elements are
+        // moved to the front, so every element is moved a constant number of times on average
+        let limit = window.saturating_mul(2).min(self.size);
+        let mut buffer = Vec::with_capacity(limit);
+        for element in self {
+            if buffer.len() == limit {
+                buffer.copy_within(limit - window + 1.., 0);
+                buffer.truncate(window - 1);
+            }
+            buffer.push(element);
+            if buffer.len() >= window {
+                reduced.push_back(f(&buffer[buffer.len() - window..]));
+            }
+        }
+
+        reduced
+    }
+
+    /// clones the elements of the slice to the end of the list
+    pub fn push_back_slice(&mut self, items: &[T])
+    where
+        T: Clone,
+    {
+        for item in items {
+            self.push_back(item.clone());
+        }
+    }
+
+    /// clones the elements of the slice to the start of the list, keeping their order
+    pub fn push_front_slice(&mut self, items: &[T])
+    where
+        T: Clone,
+    {
+        for item in items.iter().rev() {
+            self.push_front(item.clone());
+        }
+    }
+
+    /// replaces the elements in the range with the elements of the iterator by relinking nodes and
+    /// returns an iterator of the removed elements, the replacement happens even if the returned
+    /// iterator is not used, panics if the range is out of bounds
+    pub fn splice<R: RangeBounds<usize>, I: IntoIterator<Item = T>>(
+        &mut self,
+        range: R,
+        replace_with: I,
+    ) -> impl Iterator<Item = T> + use<T, R, I> {
+        let (range_start, range_end) = self.range_indices(range);
+        let mut tail = self.split_off(range_end);
+        // the removed payloads are moved out first, so that the new elements can reuse their chunk slots
+        let removed: Vec<T> = self.split_off(range_start).into_iter().collect();
+        self.extend(replace_with);
+        self.append(&mut tail);
+
+        removed.into_iter()
+    }
+
+    /// returns a reference to the element at the index or the default if the index is out of bounds
+    pub fn get_or<'a>(&'a self, index: usize, default: &'a T) -> &'a T {
+        self.get(index).unwrap_or(default)
+    }
+
+    /// returns a clone of the element at the index or the default if the index is out of bounds
+    pub fn get_cloned_or(&self, index: usize, default: T) -> T
+    where
+        T: Clone,
+    {
+        self.get(index).cloned().unwrap_or(default)
+    }
+
+    /// moves the first element to the end of the list in O(1) by relinking its node
+    pub fn cycle_front_to_back(&mut self) {
+        if self.size >= 2 {
+            self.rotate_left(1);
+        }
+    }
+
+    /// moves the last element to the start of the list in O(1) by relinking its node
+    pub fn cycle_back_to_front(&mut self) {
+        if self.size >= 2 {
+            self.rotate_right(1);
+        }
+    }
+
+    /// returns true if every element is less than or equal to the next one
+    pub fn is_sorted(&self) -> bool
+    where
+        T: PartialOrd,
+    {
+        self.is_sorted_by(T::partial_cmp)
+    }
+
+    /// returns true if the comparator orders every element as less than or equal to the next one
+    pub fn is_sorted_by<F: FnMut(&T, &T) -> Option<Ordering>>(&self, mut compare: F) -> bool {
+        self.iter().zip(self.iter().skip(1)).all(|(first, second)| {
+            matches!(
+                compare(first, second),
+                Some(Ordering::Less | Ordering::Equal)
+            )
+        })
+    }
+
+    /// returns the number of nodes found by following the XOR links from the start until the end,
+    /// unlike `len` it does not use the stored size, so it can detect corrupted links
+    pub fn count_by_traversal(&self) -> usize {
+        let mut count = 0;
+        let mut prev_ptr = null_mut();
+        let mut current_ptr = self.start;
+        while !current_ptr.is_null() {
+            let next_ptr = unsafe { xor_ptrs((*current_ptr).xor_ptr, prev_ptr) };
+            prev_ptr = current_ptr;
+            current_ptr = next_ptr;
+            count += 1;
+        }
+
+        count
+    }
+
+    /// creates a list by pushing every element of the iterator to the front, so the list is in reverse order
+    pub fn from_iter_rev<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = XorLinkedList::new();
+        for element in iter {
+            list.push_front(element);
+        }
+
+        list
+    }
+
+    /// removes the leading elements for which the predicate returns true and returns them as a list
+    /// by moving their nodes, the first element for which it returns false stays in this list
+    pub fn split_take_while<F: FnMut(&T) -> bool>(&mut self, mut pred: F) -> XorLinkedList<T> {
+        let mut prefix = self.new_sharing_arena();
+        while self.peek_front().is_some_and(&mut pred) {
+            unsafe {
+                prefix.move_front_node_from(self);
+            }
+        }
+
+        prefix
+    }
+}
+impl<T: PartialEq> PartialEq for XorLinkedList<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().zip(other.iter()).all(|(a, b)| a == b)
+    }
+}
+impl<T: Eq> Eq for XorLinkedList<T> {}
+impl<T: PartialEq> PartialEq<[T]> for XorLinkedList<T> {
+    fn eq(&self, other: &[T]) -> bool {
+        self.len() == other.len() && self.iter().eq(other)
+    }
+}
+impl<T: PartialEq> PartialEq<&[T]> for XorLinkedList<T> {
+    fn eq(&self, other: &&[T]) -> bool {
+        *self == **other
+    }
+}
+impl<T: PartialEq> PartialEq<Vec<T>> for XorLinkedList<T> {
+    fn eq(&self, other: &Vec<T>) -> bool {
+        *self == **other
+    }
+}
+impl<T: PartialEq> PartialEq<XorLinkedList<T>> for [T] {
+    fn eq(&self, other: &XorLinkedList<T>) -> bool {
+        *other == *self
+    }
+}
+impl<T: PartialEq> PartialEq<XorLinkedList<T>> for &[T] {
+    fn eq(&self, other: &XorLinkedList<T>) -> bool {
+        *other == **self
+    }
+}
+impl<T: PartialEq> PartialEq<XorLinkedList<T>> for Vec<T> {
+    fn eq(&self, other: &XorLinkedList<T>) -> bool {
+        *other == **self
+    }
+}
+impl<T: PartialOrd> PartialOrd for XorLinkedList<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+impl<T: Ord> Ord for XorLinkedList<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+impl<T: Hash> Hash for XorLinkedList<T> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.len().hash(state);
+        for element in self {
+            element.hash(state);
+        }
+    }
+}
+impl<T> Extend<T> for XorLinkedList<T> {
+    fn extend<A: IntoIterator<Item = T>>(&mut self, iter: A) {
+        for element in iter {
+            self.push_back(element);
+        }
+    }
+}
+impl<T> Index<usize> for XorLinkedList<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        self.get(index).expect(INDEX_BOUNDS_ERROR)
+    }
+}
+impl<T> IndexMut<usize> for XorLinkedList<T> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        self.get_mut(index).expect(INDEX_BOUNDS_ERROR)
+    }
+}
+impl<T: Clone> Clone for XorLinkedList<T> {
+    fn clone(&self) -> Self {
+        let mut cloned_list = XorLinkedList::new();
+        let mut prev_ptr = null_mut();
+        let mut current_ptr = self.start;
+        let mut cloned_prev_ptr = null_mut();
+        while !current_ptr.is_null() {
+            unsafe {
+                // the cloned list is valid after every node, so it is dropped correctly if clone panics
+                let new_node = cloned_list.arena.allocate((*current_ptr).payload.clone());
+                (*new_node).xor_ptr = cloned_list.end;
+                if cloned_list.end.is_null() {
+                    cloned_list.start = new_node;
+                } else {
+                    (*cloned_list.end).xor_ptr = xor_ptrs(cloned_prev_ptr, new_node);
+                }
+                cloned_prev_ptr = cloned_list.end;
+                cloned_list.end = new_node;
+                cloned_list.size += 1;
+
+                let next_ptr = xor_ptrs((*current_ptr).xor_ptr, prev_ptr);
+                prev_ptr = current_ptr;
+                current_ptr = next_ptr;
+            }
+        }
+
+        cloned_list
+    }
+}
+impl<T> Default for XorLinkedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+/// formats the list as its elements, with the `debug-internals` feature the alternate form (`{:#?}`)
+/// shows the size, the end pointers and the address and XOR pointer of every node instead
+impl<T: Debug> Debug for XorLinkedList<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        #[cfg(feature = "debug-internals")]
+        if f.alternate() {
+            return f
+                .debug_struct("XorLinkedList")
+                .field("size", &self.size)
+                .field("start", &self.start)
+                .field("end", &self.end)
+                .field("nodes", &DebugNodes(self))
+                .finish();
+        }
+
+        f.debug_list().entries(self).finish()
+    }
+}
+/// formats the list as `[a, b, c]`, passing the formatter options on to each element
+impl<T: Display> Display for XorLinkedList<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("[")?;
+        for (index, element) in self.iter().enumerate() {
+            if index > 0 {
+                f.write_str(", ")?;
+            }
+            element.fmt(f)?;
+        }
+        f.write_str("]")
+    }
+}
+// the chunk registry is only mutated by code that can not panic halfway through, so the list
+// stays unwind safe like its nodes
+impl<T: RefUnwindSafe> UnwindSafe for XorLinkedList<T> {}
+impl<T: RefUnwindSafe> RefUnwindSafe for XorLinkedList<T> {}
+impl<T> Drop for XorLinkedList<T> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+impl<T> IntoIterator for XorLinkedList<T> {
+    type Item = T;
+
+    type IntoIter = XorLinkedListIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Self::IntoIter {
+            xor_linked_list: self,
+        }
+    }
+}
+impl<'a, T> IntoIterator for &'a XorLinkedList<T> {
+    type Item = &'a T;
+    type IntoIter = RefXorLinkedListIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let current_ptr = self.start;
+        let prev_ptr = null_mut();
+
+        RefXorLinkedListIter {
+            xor_linked_list_lifetime: PhantomData,
+            current_ptr,
+            prev_ptr,
+            back_ptr: self.end,
+            back_next_ptr: null_mut(),
+            remaining: self.size,
+        }
+    }
+}
+impl<'a, T> IntoIterator for &'a mut XorLinkedList<T> {
+    type Item = &'a mut T;
+    type IntoIter = MutRefXorLinkedListIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let current_ptr = self.start;
+        let prev_ptr = null_mut();
+
+        MutRefXorLinkedListIter {
+            xor_linked_list_lifetime: PhantomData,
+            current_ptr,
+            prev_ptr,
+        }
+    }
+}
+impl<T> FromIterator<T> for XorLinkedList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = XorLinkedList::new();
+        for element in iter {
+            list.push_back(element);
+        }
+
+        list
+    }
+}
+impl<T> From<Vec<T>> for XorLinkedList<T> {
+    fn from(vec: Vec<T>) -> Self {
+        let mut list = XorLinkedList::new();
+        for element in vec {
+            list.push_back(element);
+        }
+
+        list
+    }
+}
+impl<T> From<XorLinkedList<T>> for Vec<T> {
+    fn from(list: XorLinkedList<T>) -> Self {
+        let mut vec = Vec::with_capacity(list.len());
+        vec.extend(list);
+
+        vec
+    }
+}
+impl<T, const N: usize> From<[T; N]> for XorLinkedList<T> {
+    fn from(array: [T; N]) -> Self {
+        let mut list = XorLinkedList::new();
+        for element in array {
+            list.push_back(element);
+        }
+
+        list
+    }
+}
+#[cfg(feature = "serde")]
+impl<T: Serialize> Serialize for XorLinkedList<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for XorLinkedList<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_seq(XorLinkedListVisitor {
+            marker: PhantomData,
+        })
+    }
+}
+/// deserializes a sequence by pushing each element directly into a new list
+#[cfg(feature = "serde")]
+struct XorLinkedListVisitor<T> {
+    marker: PhantomData<T>,
+}
+#[cfg(feature = "serde")]
+impl<'de, T: Deserialize<'de>> Visitor<'de> for XorLinkedListVisitor<T> {
+    type Value = XorLinkedList<T>;
+
+    fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+        formatter.write_str("a sequence")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut list = XorLinkedList::new();
+        while let Some(element) = seq.next_element()? {
+            list.push_back(element);
+        }
+
+        Ok(list)
+    }
+}
+
+pub struct XorLinkedListIter<T> {
+    xor_linked_list: XorLinkedList<T>,
+}
+impl<T> Iterator for XorLinkedListIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.xor_linked_list.pop_front()
+    }
+}
+
+pub struct RefXorLinkedListIter<'a, T> {
+    xor_linked_list_lifetime: PhantomData<&'a XorLinkedList<T>>,
+    current_ptr: *mut XorNode<T>,
+    prev_ptr: *mut XorNode<T>,
+    back_ptr: *mut XorNode<T>,
+    back_next_ptr: *mut XorNode<T>,
+    remaining: usize,
+}
+impl<'a, T> Iterator for RefXorLinkedListIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        unsafe {
+            let payload_ref = &(*self.current_ptr).payload;
+            let new_ptr = xor_ptrs((*self.current_ptr).xor_ptr, self.prev_ptr);
+            self.prev_ptr = self.current_ptr;
+            self.current_ptr = new_ptr;
+
+            Some(payload_ref)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+
+    fn fold<B, F: FnMut(B, Self::Item) -> B>(self, init: B, mut f: F) -> B {
+        let mut accumulator = init;
+        let mut prev_ptr = self.prev_ptr;
+        let mut current_ptr = self.current_ptr;
+        for _ in 0..self.remaining {
+            unsafe {
+                accumulator = f(accumulator, &(*current_ptr).payload);
+                let new_ptr = xor_ptrs((*current_ptr).xor_ptr, prev_ptr);
+                prev_ptr = current_ptr;
+                current_ptr = new_ptr;
+            }
+        }
+
+        accumulator
+    }
+}
+impl<T> ExactSizeIterator for RefXorLinkedListIter<'_, T> {}
+impl<T> DoubleEndedIterator for RefXorLinkedListIter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        unsafe {
+            let payload_ref = &(*self.back_ptr).payload;
+            let new_ptr = xor_ptrs((*self.back_ptr).xor_ptr, self.back_next_ptr);
+            self.back_next_ptr = self.back_ptr;
+            self.back_ptr = new_ptr;
+
+            Some(payload_ref)
+        }
+    }
+}
+
+pub struct MutRefXorLinkedListIter<'a, T> {
+    xor_linked_list_lifetime: PhantomData<&'a mut XorLinkedList<T>>,
+    current_ptr: *mut XorNode<T>,
+    prev_ptr: *mut XorNode<T>,
+}
+impl<'a, T> Iterator for MutRefXorLinkedListIter<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.current_ptr.is_null() {
+            return None;
+        }
+        unsafe {
+            let payload_ref = &mut (*self.current_ptr).payload;
+            let new_ptr = xor_ptrs((*self.current_ptr).xor_ptr, self.prev_ptr);
+            self.prev_ptr = self.current_ptr;
+            self.current_ptr = new_ptr;
+
+            Some(payload_ref)
+        }
+    }
+}
+
+pub struct ReverseXorLinkedListIter<T> {
+    xor_linked_list: XorLinkedList<T>,
+}
+impl<T> Iterator for ReverseXorLinkedListIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.xor_linked_list.pop_back()
+    }
+}
+
+/// iterator removing and yielding the elements for which the filter returns true,
+/// the list stays correctly linked if it is dropped early
+pub struct ExtractIf<'a, T, F: FnMut(&mut T) -> bool> {
+    xor_linked_list: &'a mut XorLinkedList<T>,
+    current_ptr: *mut XorNode<T>,
+    prev_ptr: *mut XorNode<T>,
+    filter: F,
+}
+impl<T, F: FnMut(&mut T) -> bool> Iterator for ExtractIf<'_, T, F> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.current_ptr.is_null() {
+            unsafe {
+                let next_ptr = xor_ptrs((*self.current_ptr).xor_ptr, self.prev_ptr);
+                let current_ptr = self.current_ptr;
+                self.current_ptr = next_ptr;
+                if (self.filter)(&mut (*current_ptr).payload) {
+                    return Some(
+                        self.xor_linked_list
+                            .unlink(self.prev_ptr, current_ptr, next_ptr),
+                    );
+                }
+                self.prev_ptr = current_ptr;
+            }
+        }
+
+        None
+    }
+}
+
+/// consumer of a list that can look ahead and push consumed values back to the front
+pub struct PeekableConsumer<T> {
+    xor_linked_list: XorLinkedList<T>,
+}
+impl<T> PeekableConsumer<T> {
+    /// removes and returns the next value
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.xor_linked_list.pop_front()
+    }
+
+    /// returns a reference to the next value if present
+    pub fn peek_front(&self) -> Option<&T> {
+        self.xor_linked_list.peek_front()
+    }
+
+    /// pushes a value back to the front, so that it is returned next
+    pub fn unpop_front(&mut self, value: T) {
+        self.xor_linked_list.push_front(value);
+    }
+}
+impl<T> Iterator for PeekableConsumer<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.pop_front()
+    }
+}
+
+/// creates a XOR linked list containing the elements, or n clones of a value with `xll![value; n]`
+#[macro_export]
+macro_rules! xll {
+    () => {
+        $crate::XorLinkedList::new()
+    };
+    ($value:expr; $count:expr) => {{
+        let value = $value;
+        let count: usize = $count;
+        let mut list = $crate::XorLinkedList::new();
+        for _ in 0..count {
+            list.push_back(::core::clone::Clone::clone(&value));
+        }
+        list
+    }};
+    ($($element:expr),+ $(,)?) => {{
+        let mut list = $crate::XorLinkedList::new();
+        $(list.push_back($element);)+
+        list
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        alloc::{GlobalAlloc, Layout, System},
+        cell::{Cell, RefCell},
+        collections::VecDeque,
+        rc::Rc,
+    };
+
+    use super::*;
+
+    /// counts the allocations of each test thread separately
+    struct CountingAllocator;
+    thread_local! {
+        static ALLOCATION_COUNT: Cell<usize> = const { Cell::new(0) };
+    }
+    unsafe impl GlobalAlloc for CountingAllocator {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let _ = ALLOCATION_COUNT.try_with(|count| count.set(count.get() + 1));
+            unsafe { System.alloc(layout) }
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            unsafe { System.dealloc(ptr, layout) }
+        }
+    }
+    #[global_allocator]
+    static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+    /// returns the number of allocations made by the function on the current thread
+    fn count_allocations(f: impl FnOnce()) -> usize {
+        let before = ALLOCATION_COUNT.with(Cell::get);
+        f();
+        ALLOCATION_COUNT.with(Cell::get) - before
+    }
+
+    #[test]
+    fn test_push_and_iterate() {
+        let mut list: XorLinkedList<i32> = XorLinkedList::new();
+        list.push_front(2);
+        list.push_front(1);
+        list.push_back(3);
+
+        let mut items: Vec<i32> = vec![];
+        for i in list {
+            items.push(i);
+        }
+
+        assert_eq!(1, items[0]);
+        assert_eq!(2, items[1]);
+        assert_eq!(3, items[2]);
+    }
+
+    #[test]
+    fn test_iterate_ref() {
+        let mut list: XorLinkedList<i32> = XorLinkedList::new();
+        list.push_front(3);
+        list.push_front(2);
+        list.push_front(1);
+
+        let mut items: Vec<i32> = vec![];
+        for i in &list {
+            items.push(*i);
+        }
+
+        assert_eq!(1, items[0]);
+        assert_eq!(2, items[1]);
+        assert_eq!(3, items[2]);
+    }
+
+    #[test]
+    fn test_iterate_mut_ref() {
+        let mut list: XorLinkedList<i32> = XorLinkedList::new();
+        list.push_front(3);
+        list.push_front(2);
+        list.push_front(1);
+
+        for i in &mut list {
+            *i += 100;
+        }
+
+        let mut items: Vec<i32> = vec![];
+        for i in &list {
+            items.push(*i);
+        }
+
+        assert_eq!(101, items[0]);
+        assert_eq!(102, items[1]);
+        assert_eq!(103, items[2]);
+    }
+
+    #[test]
+    fn test_get() {
+        let mut list: XorLinkedList<i32> = XorLinkedList::new();
+        list.push_front(3);
+        list.push_front(2);
+        list.push_front(1);
+
+        assert_eq!(1, *list.get(0).unwrap());
+        assert_eq!(2, *list.get(1).unwrap());
+        assert_eq!(3, *list.get(2).unwrap());
+        assert!(list.get(100).is_none());
+    }
+
+    #[test]
+    fn test_get_mut() {
+        let mut list: XorLinkedList<i32> = XorLinkedList::new();
+        list.push_front(3);
+        list.push_front(2);
+        list.push_front(1);
+
+        *list.get_mut(0).unwrap() += 100;
+        *list.get_mut(1).unwrap() += 100;
+        *list.get_mut(2).unwrap() += 100;
+
+        assert_eq!(101, *list.get(0).unwrap());
+        assert_eq!(102, *list.get(1).unwrap());
+        assert_eq!(103, *list.get(2).unwrap());
+        assert!(list.get_mut(100).is_none());
+    }
+
+    #[test]
+    fn test_index() {
+        let mut list: XorLinkedList<i32> = XorLinkedList::new();
+        list.push_front(3);
+        list.push_front(2);
+        list.push_front(1);
+
+        assert_eq!(1, list[0]);
+        assert_eq!(2, list[1]);
+        assert_eq!(3, list[2]);
+    }
+
+    #[test]
+    fn test_index_mut() {
+        let mut list: XorLinkedList<i32> = XorLinkedList::new();
+        list.push_front(3);
+        list.push_front(2);
+        list.push_front(1);
+
+        list[0] += 100;
+        list[1] += 100;
+        list[2] += 100;
+
+        assert_eq!(101, list[0]);
+        assert_eq!(102, list[1]);
+        assert_eq!(103, list[2]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_index_out_of_bounds() {
+        let mut list: XorLinkedList<i32> = XorLinkedList::new();
+        list.push_front(3);
+        list.push_front(2);
+        list.push_front(1);
+
+        let _ = list[100];
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_mut_index_out_of_bounds() {
+        let mut list: XorLinkedList<i32> = XorLinkedList::new();
+        list.push_front(3);
+        list.push_front(2);
+        list.push_front(1);
+
+        list[100] += 100;
+    }
+
+    #[test]
+    fn test_into_reverse_iter() {
+        let mut list: XorLinkedList<i32> = XorLinkedList::new();
+        list.push_front(2);
+        list.push_front(1);
+        list.push_back(3);
+
+        let mut items: Vec<i32> = vec![];
+        for i in list.into_reverse_iter() {
+            items.push(i);
+        }
+
+        assert_eq!(3, items[0]);
+        assert_eq!(2, items[1]);
+        assert_eq!(1, items[2]);
+    }
+
+    #[test]
+    fn test_peek_front() {
+        let mut list: XorLinkedList<i32> = XorLinkedList::new();
+        assert!(list.peek_front().is_none());
+        list.push_front(3);
+        list.push_front(2);
+        list.push_front(1);
+
+        assert_eq!(1, *list.peek_front().unwrap());
+    }
+
+    #[test]
+    fn test_peek_back() {
+        let mut list: XorLinkedList<i32> = XorLinkedList::new();
+        assert!(list.peek_back().is_none());
+        list.push_front(3);
+        list.push_front(2);
+        list.push_front(1);
+
+        assert_eq!(3, *list.peek_back().unwrap());
+    }
+
+    #[test]
+    fn test_peek_front_mut() {
+        let mut list: XorLinkedList<i32> = XorLinkedList::new();
+        assert!(list.peek_front_mut().is_none());
+        list.push_front(3);
+        list.push_front(2);
+        list.push_front(1);
+        *list.peek_front_mut().unwrap() += 100;
+
+        assert_eq!(101, *list.peek_front().unwrap());
+    }
+
+    #[test]
+    fn test_peek_back_mut() {
+        let mut list: XorLinkedList<i32> = XorLinkedList::new();
+        assert!(list.peek_back_mut().is_none());
+        list.push_front(3);
+        list.push_front(2);
+        list.push_front(1);
+        *list.peek_back_mut().unwrap() += 100;
+
+        assert_eq!(103, *list.peek_back().unwrap());
+    }
+
+    #[test]
+    fn test_clone() {
+        let mut list: XorLinkedList<i32> = XorLinkedList::new();
+        list.push_front(3);
+        list.push_front(2);
+        list.push_front(1);
+
+        let cloned_list = list.clone();
+        assert_eq!(3, cloned_list.len());
+
+        for (i, j) in list.into_iter().zip(cloned_list) {
+            assert_eq!(i, j);
+        }
+    }
+
+    #[test]
+    fn test_reverse_iter() {
+        let mut list: XorLinkedList<i32> = XorLinkedList::new();
+        list.push_front(3);
+        list.push_front(2);
+        list.push_front(1);
+
+        let mut iter = list.reverse_iter();
+        assert_eq!(3, *iter.next().unwrap());
+        assert_eq!(2, *iter.next().unwrap());
+        assert_eq!(1, *iter.next().unwrap());
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn test_reverse_iter_mut() {
+        let mut list: XorLinkedList<i32> = XorLinkedList::new();
+        list.push_front(3);
+        list.push_front(2);
+        list.push_front(1);
+
+        let mut iter = list.reverse_iter_mut();
+        *iter.next().unwrap() += 300;
+        *iter.next().unwrap() += 200;
+        *iter.next().unwrap() += 100;
+        assert!(iter.next().is_none());
+        drop(iter);
+
+        assert_eq!(101, list[0]);
+        assert_eq!(202, list[1]);
+        assert_eq!(303, list[2]);
+    }
+
+    #[test]
+    fn test_extend() {
+        let mut list1: XorLinkedList<i32> = XorLinkedList::new();
+        list1.push_front(3);
+        list1.push_front(2);
+        list1.push_front(1);
+
+        let mut list2: XorLinkedList<i32> = XorLinkedList::new();
+        list2.push_front(5);
+        list2.push_front(4);
+
+        list1.extend(list2);
+
+        assert_eq!(5, list1.len());
+        assert_eq!(1, list1[0]);
+        assert_eq!(2, list1[1]);
+        assert_eq!(3, list1[2]);
+        assert_eq!(4, list1[3]);
+        assert_eq!(5, list1[4]);
+    }
+
+    #[test]
+    fn test_drop() {
+        const EXPECTED_DROP_COUNT: i32 = 5;
+        let drop_counter = Rc::new(RefCell::new(0));
+        struct DropImpl {
+            drop_counter: Rc<RefCell<i32>>,
+        }
+        impl Drop for DropImpl {
+            fn drop(&mut self) {
+                *self.drop_counter.borrow_mut() += 1;
+            }
+        }
+
+        let mut list = XorLinkedList::new();
+        for _ in 0..EXPECTED_DROP_COUNT {
+            list.push_back(DropImpl {
+                drop_counter: drop_counter.clone(),
+            });
+        }
+        drop(list);
+
+        assert_eq!(EXPECTED_DROP_COUNT, *drop_counter.borrow());
+    }
+
+    #[test]
+    fn test_reverse() {
+        let mut list: XorLinkedList<i32> = XorLinkedList::new();
+        list.push_front(3);
+        list.push_front(2);
+        list.push_front(1);
+
+        list.reverse();
+
+        assert_eq!(3, list[0]);
+        assert_eq!(2, list[1]);
+        assert_eq!(1, list[2]);
+    }
+
+    #[test]
+    fn test_insert_at() {
+        let mut list: XorLinkedList<i32> = XorLinkedList::new();
+        list.insert_at(0, 4);
+        list.insert_at(0, 1);
+        list.insert_at(1, 2);
+        list.insert_at(2, 3);
+        list.insert_at(4, 7);
+        list.insert_at(4, 6);
+        list.insert_at(4, 5);
+
+        assert_eq!(7, list.len());
+        assert_eq!(1, list[0]);
+        assert_eq!(2, list[1]);
+        assert_eq!(3, list[2]);
+        assert_eq!(4, list[3]);
+        assert_eq!(5, list[4]);
+        assert_eq!(6, list[5]);
+        assert_eq!(7, list[6]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_insert_panic() {
+        let mut list: XorLinkedList<i32> = XorLinkedList::new();
+        list.insert_at(0, 1);
+        list.insert_at(3, 2);
+    }
+
+    #[test]
+    fn test_insert_at_many_near_back() {
+        let mut list = XorLinkedList::new();
+
+        for i in 0..10 {
+            list.push_back(i);
+        }
+
+        for i in 0..20 {
+            let idx = list.len() - 1;
+            list.insert_at(idx, 100 + i);
+        }
+
+        let collected: Vec<_> = list.into_iter().collect();
+
+        assert_eq!(collected.first(), Some(&0));
+        assert_eq!(collected.last(), Some(&9));
+        assert_eq!(collected.len(), 30);
+
+        let mut sorted = collected.clone();
+        sorted.sort();
+        sorted.dedup();
+        assert_eq!(sorted.len(), 30);
+    }
+
+    #[test]
+    fn test_insert_at_forward_backward_consistency() {
+        let mut list = XorLinkedList::new();
+
+        for i in 0..15 {
+            list.push_back(i);
+        }
+
+        let insert_positions = [7, 3, 10, 5, 12, 1, 8];
+        for (i, &pos) in insert_positions.iter().enumerate() {
+            list.insert_at(pos, 1000 + i);
+        }
+
+        let forward: Vec<_> = (&list).into_iter().cloned().collect();
+
+        let backward: Vec<_> = list.reverse_iter().cloned().collect();
+
+        let mut forward_reversed = forward.clone();
+        forward_reversed.reverse();
+
+        assert_eq!(forward_reversed, backward);
+    }
+
+    #[test]
+    fn test_insert_at_stress_random_positions() {
+        let mut list = XorLinkedList::new();
+
+        for i in 0..50 {
+            let pos = if list.is_empty() {
+                0
+            } else {
+                (i * 7) % list.len()
+            };
+            list.insert_at(pos, i);
+        }
+
+        let forward: Vec<_> = (&list).into_iter().cloned().collect();
+        let backward: Vec<_> = list.reverse_iter().cloned().collect();
+
+        let mut reversed_forward = forward.clone();
+        reversed_forward.reverse();
+
+        assert_eq!(reversed_forward, backward);
+    }
+
+    #[test]
+    fn test_insert_at_backward_off_by_one_detection() {
+        let mut list = XorLinkedList::new();
+
+        for i in 0..8 {
+            list.push_back(i);
+        }
+
+        list.insert_at(1, 100);
+        list.insert_at(3, 200);
+        list.insert_at(5, 300);
+
+        let idx = list.len() / 2 + 1;
+        list.insert_at(idx, 999);
+
+        assert_eq!(list[idx], 999);
+
+        assert_eq!(list[idx - 1], 300);
+        assert_eq!(list[idx + 1], 3);
+    }
+
+    #[test]
+    fn test_remove_at_ends() {
+        let mut list: XorLinkedList<i32> = XorLinkedList::new();
+        assert!(list.remove_at(0).is_none());
+        assert!(list.remove_at(1).is_none());
+        assert_eq!(0, list.len());
+
+        list.push_back(1);
+        list.push_back(2);
+        assert_eq!(1, list.remove_at(0).unwrap());
+        assert_eq!(1, list.len());
+
+        list.push_back(3);
+        assert_eq!(3, list.remove_at(1).unwrap());
+        assert_eq!(1, list.len());
+        assert_eq!(2, list[0]);
+    }
+
+    #[test]
+    fn test_remove_at_middle() {
+        let mut list: XorLinkedList<i32> = XorLinkedList::new();
+        for i in 0..10 {
+            list.push_back(i);
+        }
+
+        assert_eq!(8, list.remove_at(8).unwrap());
+        assert_eq!(9, list.len());
+
+        assert_eq!(1, list.remove_at(1).unwrap());
+        assert_eq!(8, list.len());
+
+        assert_eq!(0, list[0]);
+        assert_eq!(2, list[1]);
+        assert_eq!(3, list[2]);
+        assert_eq!(4, list[3]);
+        assert_eq!(5, list[4]);
+        assert_eq!(6, list[5]);
+        assert_eq!(7, list[6]);
+        assert_eq!(9, list[7]);
+    }
+
+    #[test]
+    fn test_clear() {
+        let mut list = XorLinkedList::new();
+        list.clear();
+        assert_eq!(0, list.size);
+
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+
+        list.clear();
+        assert_eq!(0, list.size);
+        assert!(list.peek_back().is_none());
+    }
+
+    #[test]
+    fn test_equals() {
+        let mut list1 = XorLinkedList::new();
+        list1.push_back(1);
+        list1.push_back(2);
+        let mut list2 = list1.clone();
+        assert_eq!(list1, list2);
+
+        list2.push_back(3);
+        assert_ne!(list1, list2);
+
+        list1.push_back(3);
+        assert_eq!(list1, list2);
+
+        list1[1] = 5;
+        assert_ne!(list1, list2);
+    }
+
+    #[test]
+    fn test_from_iterator() {
+        let list = XorLinkedList::from_iter([1, 2, 3]);
+
+        assert_eq!(3, list.len());
+        assert_eq!(1, list[0]);
+        assert_eq!(2, list[1]);
+        assert_eq!(3, list[2]);
+    }
+
+    #[test]
+    fn test_retain_range() {
+        let mut list = XorLinkedList::new();
+        for i in 0..8 {
+            list.push_back(i);
+        }
+
+        list.retain_range(2..6, |x| x % 2 == 0);
+
+        assert_eq!(6, list.len());
+        let forward: Vec<_> = list.iter().cloned().collect();
+        let backward: Vec<_> = list.reverse_iter().cloned().collect();
+        assert_eq!(vec![0, 1, 2, 4, 6, 7], forward);
+        assert_eq!(vec![7, 6, 4, 2, 1, 0], backward);
+    }
+
+    #[test]
+    fn test_retain_range_ends() {
+        let mut list = XorLinkedList::new();
+        for i in 0..6 {
+            list.push_back(i);
+        }
+
+        list.retain_range(.., |x| *x != 0 && *x != 5);
+        assert_eq!(4, list.len());
+        assert_eq!(1, *list.peek_front().unwrap());
+        assert_eq!(4, *list.peek_back().unwrap());
+
+        list.retain_range(1..1, |_| false);
+        assert_eq!(4, list.len());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_retain_range_out_of_bounds() {
+        let mut list = XorLinkedList::new();
+        list.push_back(1);
+        list.retain_range(0..2, |_| true);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let mut list = XorLinkedList::new();
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+
+        let serialized = serde_json::to_string(&list).unwrap();
+
+        let deserialized: XorLinkedList<i32> = serde_json::from_str(&serialized).unwrap();
+
+        assert_eq!(list, deserialized);
+    }
+
+    #[test]
+    fn test_contains() {
+        let mut list = XorLinkedList::new();
+        assert!(!list.contains(&1));
+
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+
+        assert!(list.contains(&1));
+        assert!(list.contains(&3));
+        assert!(!list.contains(&4));
+    }
+
+    #[test]
+    fn test_position() {
+        let mut list = XorLinkedList::new();
+        assert!(list.position(|x| *x == 1).is_none());
+
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+        list.push_back(2);
+
+        assert_eq!(Some(0), list.position(|x| *x == 1));
+        assert_eq!(Some(1), list.position(|x| *x == 2));
+        assert!(list.position(|x| *x == 4).is_none());
+    }
+
+    #[test]
+    fn test_swap() {
+        let mut list = XorLinkedList::new();
+        for i in 0..5 {
+            list.push_back(i);
+        }
+
+        list.swap(2, 2);
+        assert_eq!(2, list[2]);
+
+        list.swap(0, 4);
+        assert_eq!(4, list[0]);
+        assert_eq!(0, list[4]);
+
+        list.swap(3, 1);
+        let items: Vec<_> = list.iter().cloned().collect();
+        assert_eq!(vec![4, 3, 2, 1, 0], items);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_swap_out_of_bounds() {
+        let mut list = XorLinkedList::new();
+        list.push_back(1);
+        list.swap(0, 1);
+    }
+
+    #[test]
+    fn test_sorted_prefix_len() {
+        let list = XorLinkedList::from_iter([1, 2, 3, 2, 5]);
+        assert_eq!(3, list.sorted_prefix_len());
+
+        let list = XorLinkedList::from_iter([1, 1, 2]);
+        assert_eq!(3, list.sorted_prefix_len());
+
+        let list: XorLinkedList<i32> = XorLinkedList::new();
+        assert_eq!(0, list.sorted_prefix_len());
+    }
+
+    #[test]
+    fn test_split_off_where() {
+        let mut list = XorLinkedList::from_iter(0..6);
+
+        let tail = list.split_off_where(|x| *x == 3);
+
+        assert_eq!(3, list.len());
+        assert_eq!(3, tail.len());
+        assert_eq!(vec![0, 1, 2], list.iter().cloned().collect::<Vec<_>>());
+        assert_eq!(
+            vec![2, 1, 0],
+            list.reverse_iter().cloned().collect::<Vec<_>>()
+        );
+        assert_eq!(vec![3, 4, 5], tail.iter().cloned().collect::<Vec<_>>());
+        assert_eq!(
+            vec![5, 4, 3],
+            tail.reverse_iter().cloned().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_split_off_where_no_match() {
+        let mut list = XorLinkedList::from_iter(0..6);
+
+        let tail = list.split_off_where(|x| *x > 10);
+
+        assert!(tail.is_empty());
+        assert_eq!(6, list.len());
+        assert_eq!(
+            vec![0, 1, 2, 3, 4, 5],
+            list.iter().cloned().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_split_off_where_first() {
+        let mut list = XorLinkedList::from_iter(0..3);
+
+        let tail = list.split_off_where(|x| *x == 0);
+
+        assert!(list.is_empty());
+        assert!(list.peek_front().is_none());
+        assert_eq!(vec![0, 1, 2], tail.iter().cloned().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_split_off() {
+        let mut list = XorLinkedList::from_iter(0..5);
+
+        let tail = list.split_off(2);
+        assert_eq!(vec![0, 1], list.iter().cloned().collect::<Vec<_>>());
+        assert_eq!(
+            vec![4, 3, 2],
+            tail.reverse_iter().cloned().collect::<Vec<_>>()
+        );
+
+        let empty_tail = list.split_off(2);
+        assert!(empty_tail.is_empty());
+        assert_eq!(2, list.len());
+
+        let whole = list.split_off(0);
+        assert!(list.is_empty());
+        assert_eq!(vec![0, 1], whole.iter().cloned().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_append() {
+        let mut list1 = XorLinkedList::from_iter(0..3);
+        let mut list2 = XorLinkedList::from_iter(3..5);
+
+        list1.append(&mut list2);
+
+        assert!(list2.is_empty());
+        assert_eq!(5, list1.len());
+        assert_eq!(
+            vec![0, 1, 2, 3, 4],
+            list1.iter().cloned().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![4, 3, 2, 1, 0],
+            list1.reverse_iter().cloned().collect::<Vec<_>>()
+        );
+
+        let mut empty = XorLinkedList::new();
+        empty.append(&mut list1);
+        assert!(list1.is_empty());
+        assert_eq!(5, empty.len());
+
+        empty.append(&mut list1);
+        assert_eq!(5, empty.len());
+    }
+
+    #[test]
+    fn test_rotate() {
+        for len in 0..8 {
+            for mid in 0..=len {
+                let mut list = XorLinkedList::from_iter(0..len);
+                let mut deque: VecDeque<_> = (0..len).collect();
+
+                list.rotate_left(mid);
+                deque.rotate_left(mid);
+                assert_eq!(
+                    deque.iter().collect::<Vec<_>>(),
+                    list.iter().collect::<Vec<_>>()
+                );
+                assert_eq!(
+                    deque.iter().rev().collect::<Vec<_>>(),
+                    list.reverse_iter().collect::<Vec<_>>()
+                );
+
+                list.rotate_right(mid);
+                deque.rotate_right(mid);
+                assert_eq!(
+                    deque.iter().collect::<Vec<_>>(),
+                    list.iter().collect::<Vec<_>>()
+                );
+                assert_eq!(
+                    deque.iter().rev().collect::<Vec<_>>(),
+                    list.reverse_iter().collect::<Vec<_>>()
+                );
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_rotate_out_of_bounds() {
+        let mut list = XorLinkedList::from_iter(0..3);
+        list.rotate_left(4);
+    }
+
+    #[test]
+    fn test_dedup() {
+        let mut list = XorLinkedList::from_iter([1, 1, 1, 2, 3, 3, 2, 4, 4, 4]);
+
+        list.dedup();
+
+        assert_eq!(5, list.len());
+        assert_eq!(
+            vec![1, 2, 3, 2, 4],
+            list.iter().cloned().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![4, 2, 3, 2, 1],
+            list.reverse_iter().cloned().collect::<Vec<_>>()
+        );
+        assert_eq!(4, *list.peek_back().unwrap());
+    }
+
+    #[test]
+    fn test_dedup_all_equal() {
+        let mut list = XorLinkedList::from_iter([7, 7, 7, 7]);
+
+        list.dedup();
+
+        assert_eq!(1, list.len());
+        assert_eq!(7, *list.peek_front().unwrap());
+        assert_eq!(7, *list.peek_back().unwrap());
+
+        let mut empty: XorLinkedList<i32> = XorLinkedList::new();
+        empty.dedup();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_dedup_by_key() {
+        let mut list = XorLinkedList::from_iter([10, 11, 20, 21, 22, 30, 15]);
+
+        list.dedup_by_key(|x| *x / 10);
+
+        assert_eq!(
+            vec![10, 20, 30, 15],
+            list.iter().cloned().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![15, 30, 20, 10],
+            list.reverse_iter().cloned().collect::<Vec<_>>()
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_dedup_by_hash() {
+        #[derive(Debug, Clone, PartialEq)]
+        struct Colliding(i32);
+        impl Hash for Colliding {
+            fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+                (self.0 % 2).hash(state);
+            }
+        }
+
+        let values = [1, 1, 3, 3, 3, 5, 2, 4, 4, 1, 1];
+        let mut list = XorLinkedList::from_iter(values.map(Colliding));
+        let mut expected = XorLinkedList::from_iter(values.map(Colliding));
+
+        list.dedup_by_hash();
+        expected.dedup();
+
+        assert_eq!(expected, list);
+        assert_eq!(
+            vec![1, 3, 5, 2, 4, 1],
+            list.iter().map(|x| x.0).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![1, 4, 2, 5, 3, 1],
+            list.reverse_iter().map(|x| x.0).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_append_cloned() {
+        let mut list = XorLinkedList::from_iter([1, 2]);
+        let other = XorLinkedList::from_iter([3, 4, 5]);
+
+        list.append_cloned(&other);
+
+        assert_eq!(
+            vec![1, 2, 3, 4, 5],
+            list.iter().cloned().collect::<Vec<_>>()
+        );
+        assert_eq!(3, other.len());
+        assert_eq!(vec![3, 4, 5], other.iter().cloned().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_sort() {
+        let mut list = XorLinkedList::from_iter([5, 3, 9, 1, 1, 7, 0, 4, 8, 2, 6]);
+
+        list.sort();
+
+        assert_eq!(11, list.len());
+        assert_eq!(
+            vec![0, 1, 1, 2, 3, 4, 5, 6, 7, 8, 9],
+            list.iter().cloned().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![9, 8, 7, 6, 5, 4, 3, 2, 1, 1, 0],
+            list.reverse_iter().cloned().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_sort_sorted_and_reversed() {
+        for len in 0..40 {
+            let mut sorted = XorLinkedList::from_iter(0..len);
+            sorted.sort();
+            assert_eq!(
+                (0..len).collect::<Vec<_>>(),
+                sorted.iter().cloned().collect::<Vec<_>>()
+            );
+            assert_eq!(
+                (0..len).rev().collect::<Vec<_>>(),
+                sorted.reverse_iter().cloned().collect::<Vec<_>>()
+            );
+
+            let mut reversed = XorLinkedList::from_iter((0..len).rev());
+            reversed.sort();
+            assert_eq!(len as usize, reversed.len());
+            assert_eq!(
+                (0..len).collect::<Vec<_>>(),
+                reversed.iter().cloned().collect::<Vec<_>>()
+            );
+            assert_eq!(
+                (0..len).rev().collect::<Vec<_>>(),
+                reversed.reverse_iter().cloned().collect::<Vec<_>>()
+            );
+        }
+    }
+
+    #[test]
+    fn test_sort_by_stable() {
+        let pairs = [
+            (3, 'a'),
+            (1, 'b'),
+            (3, 'c'),
+            (2, 'd'),
+            (1, 'e'),
+            (3, 'f'),
+            (2, 'g'),
+        ];
+        let mut list = XorLinkedList::from_iter(pairs);
+        let mut expected = pairs.to_vec();
+
+        list.sort_by(|a, b| a.0.cmp(&b.0));
+        expected.sort_by_key(|a| a.0);
+
+        assert_eq!(expected, list.iter().cloned().collect::<Vec<_>>());
+        assert_eq!(
+            expected.into_iter().rev().collect::<Vec<_>>(),
+            list.reverse_iter().cloned().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_push_iterate_drop() {
+        let mut list = XorLinkedList::new();
+        for i in 0..4 {
+            list.push_back(i.to_string());
+            list.push_front((-i).to_string());
+        }
+        list.insert_at(3, "middle".to_string());
+        list.remove_at(5);
+
+        assert_eq!(8, list.iter().count());
+        assert_eq!(8, list.reverse_iter().count());
+        for element in list.iter_mut() {
+            element.push('!');
+        }
+        assert_eq!("-3!", list.peek_front().unwrap());
+        assert_eq!("3!", list.peek_back().unwrap());
+        drop(list);
+    }
+
+    #[test]
+    fn test_monotonic_runs() {
+        let list = XorLinkedList::from_iter([1, 2, 3, 1, 2, 0]);
+
+        let runs: Vec<_> = list.monotonic_runs().collect();
+
+        assert_eq!(vec![vec![&1, &2, &3], vec![&1, &2], vec![&0]], runs);
+
+        let empty: XorLinkedList<i32> = XorLinkedList::new();
+        assert_eq!(0, empty.monotonic_runs().count());
+    }
+
+    #[test]
+    fn test_overwrite_range() {
+        let mut list = XorLinkedList::from_iter(0..6);
+
+        list.overwrite_range(2, [20, 30]);
+        assert_eq!(
+            vec![0, 1, 20, 30, 4, 5],
+            list.iter().cloned().collect::<Vec<_>>()
+        );
+
+        list.overwrite_range(4, [40, 50, 60, 70]);
+        assert_eq!(6, list.len());
+        assert_eq!(
+            vec![0, 1, 20, 30, 40, 50],
+            list.iter().cloned().collect::<Vec<_>>()
+        );
+
+        list.overwrite_range(6, [100]);
+        assert_eq!(
+            vec![0, 1, 20, 30, 40, 50],
+            list.iter().cloned().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_from_vec_round_trip() {
+        let list: XorLinkedList<_> = vec![1, 2, 3].into();
+        assert_eq!(3, list.len());
+        assert_eq!(1, list[0]);
+        assert_eq!(2, list[1]);
+        assert_eq!(3, list[2]);
+
+        let vec: Vec<_> = list.into();
+        assert_eq!(vec![1, 2, 3], vec);
+
+        let empty: Vec<i32> = XorLinkedList::from(Vec::new()).into();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_from_array() {
+        let list = XorLinkedList::from([1, 2, 3]);
+
+        assert_eq!(3, list.len());
+        assert_eq!(1, list[0]);
+        assert_eq!(2, list[1]);
+        assert_eq!(3, list[2]);
+    }
+
+    #[test]
+    fn test_count_rising_edges() {
+        let list = XorLinkedList::from([false, true, true, false, true]);
+        assert_eq!(2, list.count_rising_edges(|x| *x));
+
+        let list = XorLinkedList::from([true, false, true]);
+        assert_eq!(2, list.count_rising_edges(|x| *x));
+
+        let empty: XorLinkedList<bool> = XorLinkedList::new();
+        assert_eq!(0, empty.count_rising_edges(|x| *x));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_into_grouped() {
+        let list = XorLinkedList::from([
+            ("fruit", "apple"),
+            ("vegetable", "carrot"),
+            ("fruit", "banana"),
+            ("fruit", "cherry"),
+            ("vegetable", "leek"),
+        ]);
+
+        let groups = list.into_grouped(|(category, _)| *category);
+
+        assert_eq!(2, groups.len());
+        assert_eq!(
+            vec!["apple", "banana", "cherry"],
+            groups["fruit"]
+                .iter()
+                .map(|(_, name)| *name)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec!["carrot", "leek"],
+            groups["vegetable"]
+                .iter()
+                .map(|(_, name)| *name)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_ordering() {
+        let list = XorLinkedList::from([1, 2, 3]);
+
+        assert_eq!(Ordering::Equal, list.cmp(&XorLinkedList::from([1, 2, 3])));
+        assert!(XorLinkedList::from([1, 2]) < list);
+        assert!(list > XorLinkedList::from([1, 2]));
+        assert!(XorLinkedList::new() < list);
+        assert!(XorLinkedList::from([0, 5, 5]) < list);
+        assert!(XorLinkedList::from([2]) > list);
+
+        let float_list = XorLinkedList::from([1.0, f64::NAN]);
+        assert!(
+            float_list
+                .partial_cmp(&XorLinkedList::from([1.0, 2.0]))
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_pop_front_if() {
+        let mut list = XorLinkedList::from([1, 2, 3]);
+
+        assert!(list.pop_front_if(|x| *x == 2).is_none());
+        assert_eq!(3, list.len());
+        assert_eq!(Some(1), list.pop_front_if(|x| *x == 1));
+        assert_eq!(2, list.len());
+        assert_eq!(2, *list.peek_front().unwrap());
+
+        let mut empty: XorLinkedList<i32> = XorLinkedList::new();
+        assert!(empty.pop_front_if(|_| true).is_none());
+    }
+
+    #[test]
+    fn test_pop_back_if() {
+        let mut list = XorLinkedList::from([1, 2, 3]);
+
+        assert!(list.pop_back_if(|x| *x == 1).is_none());
+        assert_eq!(3, list.len());
+        assert_eq!(Some(3), list.pop_back_if(|x| *x == 3));
+        assert_eq!(2, list.len());
+        assert_eq!(2, *list.peek_back().unwrap());
+    }
+
+    #[test]
+    fn test_insert_sorted_many() {
+        let mut list = XorLinkedList::from([1, 4, 6, 9]);
+
+        list.insert_sorted_many([7, 0, 5, 10, 4]);
+
+        assert_eq!(9, list.len());
+        assert_eq!(
+            vec![0, 1, 4, 4, 5, 6, 7, 9, 10],
+            list.iter().cloned().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![10, 9, 7, 6, 5, 4, 4, 1, 0],
+            list.reverse_iter().cloned().collect::<Vec<_>>()
+        );
+
+        let mut empty = XorLinkedList::new();
+        empty.insert_sorted_many([3, 1, 2]);
+        assert_eq!(vec![1, 2, 3], empty.iter().cloned().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_to_vec_and_into_vec() {
+        let list = XorLinkedList::from([1, 2, 3]);
+
+        let cloned = list.to_vec();
+        assert_eq!(vec![1, 2, 3], cloned);
+        assert_eq!(3, list.len());
+
+        let drained = list.into_vec();
+        assert_eq!(3, drained.len());
+        assert_eq!(vec![1, 2, 3], drained);
+    }
+
+    #[test]
+    fn test_xll_macro() {
+        let mut expected = XorLinkedList::new();
+        expected.push_back(1);
+        expected.push_back(2);
+        expected.push_back(3);
+        assert_eq!(expected, xll![1, 2, 3]);
+        assert_eq!(expected, xll![1, 2, 3,]);
+
+        let zeros = xll![0; 4];
+        assert_eq!(4, zeros.len());
+        assert!(zeros.iter().all(|x| *x == 0));
+
+        let empty: XorLinkedList<i32> = xll![];
+        assert!(empty.is_empty());
+        assert!(xll![String::new(); 0].is_empty());
+    }
+
+    #[test]
+    fn test_clear_drop_order() {
+        struct DropImpl {
+            id: i32,
+            drop_order: Rc<RefCell<Vec<i32>>>,
+        }
+        impl Drop for DropImpl {
+            fn drop(&mut self) {
+                self.drop_order.borrow_mut().push(self.id);
+            }
+        }
+
+        let drop_order = Rc::new(RefCell::new(vec![]));
+        let mut list = XorLinkedList::new();
+        for id in 0..4 {
+            list.push_back(DropImpl {
+                id,
+                drop_order: drop_order.clone(),
+            });
+        }
+        list.clear();
+        assert_eq!(vec![0, 1, 2, 3], *drop_order.borrow());
+
+        drop_order.borrow_mut().clear();
+        for id in 0..4 {
+            list.push_back(DropImpl {
+                id,
+                drop_order: drop_order.clone(),
+            });
+        }
+        list.clear_back();
+        assert!(list.is_empty());
+        assert_eq!(vec![3, 2, 1, 0], *drop_order.borrow());
+    }
+
+    #[test]
+    fn test_collect_capped() {
+        let list = XorLinkedList::from_iter(0..10);
+
+        assert_eq!(vec![0, 1, 2], list.collect_capped(3));
+        assert_eq!(10, list.collect_capped(20).len());
+        assert!(list.collect_capped(0).is_empty());
+    }
+
+    #[test]
+    fn test_remove_first() {
+        let mut list = XorLinkedList::from([1, 2, 3, 2, 4]);
+
+        assert!(list.remove_first(&2));
+        assert_eq!(vec![1, 3, 2, 4], list.iter().cloned().collect::<Vec<_>>());
+        assert_eq!(
+            vec![4, 2, 3, 1],
+            list.reverse_iter().cloned().collect::<Vec<_>>()
+        );
+
+        assert!(!list.remove_first(&5));
+        assert_eq!(4, list.len());
+
+        assert!(list.remove_first(&1));
+        assert_eq!(3, *list.peek_front().unwrap());
+        assert!(list.remove_first(&4));
+        assert_eq!(2, *list.peek_back().unwrap());
+        assert_eq!(2, list.len());
+    }
+
+    #[test]
+    fn test_remove_first_only_element() {
+        let mut list = XorLinkedList::from([1]);
+
+        assert!(list.remove_first(&1));
+        assert!(list.is_empty());
+        assert!(list.peek_front().is_none());
+        assert!(list.peek_back().is_none());
+
+        list.push_back(2);
+        assert_eq!(vec![2], list.iter().cloned().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_swap_adjacent() {
+        let mut list = XorLinkedList::from([1, 2, 3, 4, 5]);
+
+        assert!(list.swap_adjacent(0));
+        assert!(list.swap_adjacent(3));
+        assert_eq!(
+            vec![2, 1, 3, 5, 4],
+            list.iter().cloned().collect::<Vec<_>>()
+        );
+
+        assert!(!list.swap_adjacent(4));
+        assert!(!list.swap_adjacent(usize::MAX));
+        assert_eq!(
+            vec![2, 1, 3, 5, 4],
+            list.iter().cloned().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_positions() {
+        let list = XorLinkedList::from([1, 2, 1, 3, 1]);
+
+        assert_eq!(vec![0, 2, 4], list.positions(|x| *x == 1));
+        assert!(list.positions(|x| *x == 5).is_empty());
+    }
+
+    #[test]
+    fn test_insert_sorted() {
+        let mut list = XorLinkedList::new();
+        assert_eq!(0, list.insert_sorted(5));
+        assert_eq!(0, list.insert_sorted(1));
+        assert_eq!(2, list.insert_sorted(9));
+        assert_eq!(2, list.insert_sorted(5));
+        assert_eq!(vec![1, 5, 5, 9], list.iter().cloned().collect::<Vec<_>>());
+        assert_eq!(
+            vec![9, 5, 5, 1],
+            list.reverse_iter().cloned().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_insert_sorted_random() {
+        let mut list = XorLinkedList::new();
+        let mut seed: u64 = 42;
+        for _ in 0..200 {
+            seed = seed
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            let value = (seed >> 33) % 100;
+            let index = list.insert_sorted(value);
+            assert_eq!(value, list[index]);
+        }
+
+        let forward: Vec<_> = list.iter().cloned().collect();
+        let mut backward: Vec<_> = list.reverse_iter().cloned().collect();
+        backward.reverse();
+        assert_eq!(200, forward.len());
+        assert!(forward.is_sorted());
+        assert_eq!(forward, backward);
+    }
+
+    #[test]
+    fn test_insert_sorted_by() {
+        let mut list = XorLinkedList::from([9, 5, 1]);
+
+        assert_eq!(1, list.insert_sorted_by(7, |a, b| b.cmp(a)));
+        assert_eq!(4, list.insert_sorted_by(0, |a, b| b.cmp(a)));
+        assert_eq!(
+            vec![9, 7, 5, 1, 0],
+            list.iter().cloned().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_get_pair_mut() {
+        let mut list = XorLinkedList::from([1, 2, 3, 4]);
+
+        let (first, second) = list.get_pair_mut(3, 0).unwrap();
+        *first += 10;
+        *second *= 100;
+        assert_eq!(
+            vec![100, 2, 3, 14],
+            list.iter().cloned().collect::<Vec<_>>()
+        );
+
+        assert!(list.get_pair_mut(1, 1).is_none());
+        assert!(list.get_pair_mut(1, 4).is_none());
+        assert!(list.get_pair_mut(4, 1).is_none());
+    }
+
+    #[test]
+    fn test_iter_rotated() {
+        let list = XorLinkedList::from_iter(0..5);
+
+        assert_eq!(
+            vec![2, 3, 4, 0, 1],
+            list.iter_rotated(2).cloned().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![0, 1, 2, 3, 4],
+            list.iter_rotated(0).cloned().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![0, 1, 2, 3, 4],
+            list.iter_rotated(5).cloned().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_fold_rotated() {
+        let list = XorLinkedList::from_iter(0..5);
+
+        let order = list.fold_rotated(2, vec![], |mut acc, x| {
+            acc.push(*x);
+            acc
+        });
+        assert_eq!(vec![2, 3, 4, 0, 1], order);
+        assert_eq!(23401, list.fold_rotated(2, 0, |acc, x| acc * 10 + x));
+
+        let empty: XorLinkedList<i32> = XorLinkedList::new();
+        assert_eq!(7, empty.fold_rotated(0, 7, |acc, x| acc + x));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_fold_rotated_out_of_bounds() {
+        let list = XorLinkedList::from_iter(0..5);
+        list.fold_rotated(6, 0, |acc, x| acc + x);
+    }
+
+    #[test]
+    fn test_try_get_range() {
+        let list = XorLinkedList::from_iter(0..6);
+
+        assert_eq!(vec![&2, &3, &4], list.try_get_range(2..5).unwrap());
+        assert_eq!(vec![&4, &5], list.try_get_range(4..).unwrap());
+        assert_eq!(vec![&0, &1], list.try_get_range(..=1).unwrap());
+        assert!(list.try_get_range(3..3).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_try_get_range_out_of_bounds() {
+        let list = XorLinkedList::from_iter(0..6);
+
+        let error = list.try_get_range(4..8).unwrap_err();
+        assert_eq!(4, error.start);
+        assert_eq!(8, error.end);
+        assert_eq!(6, error.len);
+        assert_eq!(
+            "range 4..8 is out of bounds for a list of length 6",
+            error.to_string()
+        );
+
+        let error = list.try_get_range(7..).unwrap_err();
+        assert_eq!(7, error.start);
+        assert_eq!(6, error.end);
+    }
+
+    #[test]
+    fn test_prepend() {
+        let mut list = XorLinkedList::from([3, 4]);
+        let mut other = XorLinkedList::from([1, 2]);
+
+        list.prepend(&mut other);
+
+        assert!(other.is_empty());
+        assert_eq!(4, list.len());
+        assert_eq!(vec![1, 2, 3, 4], list.iter().cloned().collect::<Vec<_>>());
+        assert_eq!(
+            vec![4, 3, 2, 1],
+            list.reverse_iter().cloned().collect::<Vec<_>>()
+        );
+
+        list.prepend(&mut other);
+        assert_eq!(4, list.len());
+
+        let mut empty = XorLinkedList::new();
+        empty.prepend(&mut list);
+        assert!(list.is_empty());
+        assert_eq!(vec![1, 2, 3, 4], empty.iter().cloned().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_iter_fold() {
+        let list = XorLinkedList::from_iter(0..1000u64);
+
+        let mut manual_sum = 0;
+        for i in 0..list.len() {
+            manual_sum += list[i];
+        }
+        let ordered = list.iter().fold(vec![], |mut acc, x| {
+            acc.push(*x);
+            acc
+        });
+
+        assert_eq!(manual_sum, list.iter().sum::<u64>());
+        assert_eq!(manual_sum, list.reverse_iter().sum::<u64>());
+        assert_eq!((0..1000).collect::<Vec<_>>(), ordered);
+
+        let mut order = vec![];
+        list.iter().take(3).for_each(|x| order.push(*x));
+        list.iter().skip(998).for_each(|x| order.push(*x));
+        assert_eq!(vec![0, 1, 2, 998, 999], order);
+    }
+
+    #[test]
+    fn test_merge_dedup() {
+        let mut list = XorLinkedList::from([1, 2, 3]);
+
+        list.merge_dedup(XorLinkedList::from([2, 3, 4]));
+
+        assert_eq!(vec![1, 2, 3, 4], list.iter().cloned().collect::<Vec<_>>());
+        assert_eq!(
+            vec![4, 3, 2, 1],
+            list.reverse_iter().cloned().collect::<Vec<_>>()
+        );
+
+        list.merge_dedup(XorLinkedList::new());
+        assert_eq!(4, list.len());
+    }
+
+    #[test]
+    fn test_into_vec_reversed() {
+        let list = XorLinkedList::from([1, 2, 3]);
+
+        assert_eq!(vec![3, 2, 1], list.into_vec_reversed());
+        assert!(XorLinkedList::<i32>::new().into_vec_reversed().is_empty());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_structs() {
+        #[derive(Debug, PartialEq, serde::Deserialize)]
+        struct Record<'a> {
+            id: u32,
+            name: &'a str,
+        }
+
+        let json = r#"[{"id": 1, "name": "first"}, {"id": 2, "name": "second"}]"#;
+        let list: XorLinkedList<Record> = serde_json::from_str(json).unwrap();
+
+        assert_eq!(2, list.len());
+        assert_eq!(
+            Record {
+                id: 1,
+                name: "first"
+            },
+            list[0]
+        );
+        assert_eq!(
+            Record {
+                id: 2,
+                name: "second"
+            },
+            list[1]
+        );
+
+        let empty: XorLinkedList<Record> = serde_json::from_str("[]").unwrap();
+        assert!(empty.is_empty());
+        assert!(serde_json::from_str::<XorLinkedList<Record>>("{}").is_err());
+    }
+
+    #[test]
+    fn test_equals_slice_and_vec() {
+        let list = XorLinkedList::from([1, 2, 3]);
+
+        assert_eq!(list, vec![1, 2, 3]);
+        assert_eq!(vec![1, 2, 3], list);
+        assert!(list == [1, 2, 3][..]);
+        assert!([1, 2, 3][..] == list);
+        assert_eq!(list, &[1, 2, 3][..]);
+        assert_eq!(&[1, 2, 3][..], list);
+
+        assert_ne!(list, vec![1, 2]);
+        assert_ne!(list, vec![1, 2, 3, 4]);
+        assert_ne!(list, vec![1, 5, 3]);
+        assert_ne!(vec![1, 2], list);
+        assert!(list != [1, 2][..]);
+        assert_ne!(list, &[1, 2, 3, 4][..]);
+        assert_eq!(XorLinkedList::<i32>::new(), Vec::new());
+    }
+
+    #[test]
+    fn test_retain_dedup() {
+        let mut list = XorLinkedList::from([1, 1, 2, 2, 3]);
+        list.retain_dedup(|x| x % 2 == 1);
+        assert_eq!(vec![1, 3], list.iter().cloned().collect::<Vec<_>>());
+        assert_eq!(vec![3, 1], list.reverse_iter().cloned().collect::<Vec<_>>());
+
+        let mut list = XorLinkedList::from([2, 1, 2, 1, 5, 5]);
+        list.retain_dedup(|x| x % 2 == 1);
+        assert_eq!(vec![1, 5], list.iter().cloned().collect::<Vec<_>>());
+        assert_eq!(5, *list.peek_back().unwrap());
+    }
+
+    #[test]
+    fn test_count_pairs() {
+        let list = XorLinkedList::from([1, 3, 2, 4]);
+        assert_eq!(2, list.count_pairs(|a, b| a < b));
+
+        assert_eq!(0, XorLinkedList::from([1]).count_pairs(|_, _| true));
+        assert_eq!(0, XorLinkedList::<i32>::new().count_pairs(|_, _| true));
+    }
+
+    #[test]
+    fn test_display() {
+        let list = XorLinkedList::from(["a", "b", "c"]);
+        assert_eq!("[a, b, c]", list.to_string());
+
+        let empty: XorLinkedList<i32> = XorLinkedList::new();
+        assert_eq!("[]", empty.to_string());
+
+        let numbers = XorLinkedList::from([1, 20, 3]);
+        assert_eq!("[ 1, 20,  3]", format!("{numbers:>2}"));
+    }
+
+    #[test]
+    fn test_join() {
+        let list = XorLinkedList::from([1, 2, 3]);
+        assert_eq!("1 - 2 - 3", list.join(" - "));
+        assert_eq!("123", list.join(""));
+        assert_eq!("", XorLinkedList::<i32>::new().join(", "));
+    }
+
+    #[test]
+    fn test_truncate() {
+        let mut list = XorLinkedList::from_iter(0..5);
+
+        list.truncate(10);
+        assert_eq!(5, list.len());
+
+        list.truncate(3);
+        assert_eq!(vec![0, 1, 2], list.iter().cloned().collect::<Vec<_>>());
+        assert_eq!(
+            vec![2, 1, 0],
+            list.reverse_iter().cloned().collect::<Vec<_>>()
+        );
+
+        list.truncate(0);
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn test_resize() {
+        let mut list = XorLinkedList::from([1, 2]);
+
+        list.resize(4, 7);
+        assert_eq!(vec![1, 2, 7, 7], list.iter().cloned().collect::<Vec<_>>());
+
+        list.resize(4, 9);
+        assert_eq!(vec![1, 2, 7, 7], list.iter().cloned().collect::<Vec<_>>());
+
+        list.resize(1, 9);
+        assert_eq!(vec![1], list.iter().cloned().collect::<Vec<_>>());
+        assert_eq!(1, *list.peek_back().unwrap());
+
+        list.resize(0, 9);
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn test_resize_with() {
+        let mut list = XorLinkedList::from([0]);
+        let mut counter = 0;
+
+        list.resize_with(4, || {
+            counter += 1;
+            counter * 10
+        });
+        assert_eq!(
+            vec![0, 10, 20, 30],
+            list.iter().cloned().collect::<Vec<_>>()
+        );
+
+        list.resize_with(2, || unreachable!());
+        assert_eq!(vec![0, 10], list.iter().cloned().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_peekable_consumer() {
+        let mut consumer = XorLinkedList::from([1, 2, 3]).into_peekable_consumer();
+
+        assert_eq!(Some(&1), consumer.peek_front());
+        assert_eq!(Some(1), consumer.pop_front());
+        assert_eq!(Some(2), consumer.pop_front());
+        consumer.unpop_front(2);
+        consumer.unpop_front(1);
+        assert_eq!(Some(&1), consumer.peek_front());
+
+        assert_eq!(vec![1, 2, 3], consumer.collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_halves_mut() {
+        let mut list = XorLinkedList::from_iter(0..5);
+
+        let (first_half, second_half) = list.halves_mut();
+        let mut second_order = vec![];
+        for (first, second) in first_half.zip(second_half) {
+            *first += 100;
+            second_order.push(*second);
+            *second += 200;
+        }
+
+        assert_eq!(vec![4, 3], second_order);
+        assert_eq!(
+            vec![100, 101, 2, 203, 204],
+            list.iter().cloned().collect::<Vec<_>>()
+        );
+
+        let (first_half, second_half) = list.halves_mut();
+        assert_eq!(2, first_half.count());
+        assert_eq!(
+            vec![204, 203, 2],
+            second_half.map(|x| *x).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_extract_if() {
+        let mut list = XorLinkedList::from_iter(0..10);
+
+        let extracted: Vec<_> = list.extract_if(|x| *x % 2 == 0).collect();
+
+        assert_eq!(vec![0, 2, 4, 6, 8], extracted);
+        assert_eq!(
+            vec![1, 3, 5, 7, 9],
+            list.iter().cloned().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![9, 7, 5, 3, 1],
+            list.reverse_iter().cloned().collect::<Vec<_>>()
+        );
+
+        assert_eq!(0, list.extract_if(|_| false).count());
+        assert_eq!(5, list.len());
+
+        let last: Vec<_> = list.extract_if(|x| *x == 9).collect();
+        assert_eq!(vec![9], last);
+        assert_eq!(7, *list.peek_back().unwrap());
+
+        assert_eq!(4, list.extract_if(|_| true).count());
+        assert!(list.is_empty());
+        assert!(list.peek_front().is_none());
+    }
+
+    #[test]
+    fn test_extract_if_dropped_early() {
+        let mut list = XorLinkedList::from_iter(0..10);
+
+        {
+            let mut iter = list.extract_if(|x| {
+                *x += 100;
+                *x % 3 == 0
+            });
+            assert_eq!(Some(102), iter.next());
+            assert_eq!(Some(105), iter.next());
+        }
+
+        assert_eq!(
+            vec![100, 101, 103, 104, 6, 7, 8, 9],
+            list.iter().cloned().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![9, 8, 7, 6, 104, 103, 101, 100],
+            list.reverse_iter().cloned().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_with_capacity() {
+        let mut list = XorLinkedList::with_capacity(4);
+        for i in 0..10 {
+            list.push_back(i);
+        }
+        list.push_front(-1);
+        list.insert_at(5, 100);
+
+        assert_eq!(12, list.len());
+        assert_eq!(
+            vec![-1, 0, 1, 2, 3, 100, 4, 5, 6, 7, 8, 9],
+            list.iter().cloned().collect::<Vec<_>>()
+        );
+        assert_eq!(Some(100), list.remove_at(5));
+        assert_eq!(Some(-1), list.pop_front());
+        assert_eq!(Some(9), list.pop_back());
+
+        for i in 0..5 {
+            list.push_front(i);
+            list.pop_back();
+        }
+        assert_eq!(
+            vec![4, 3, 2, 1, 0, 0, 1, 2, 3],
+            list.iter().cloned().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![3, 2, 1, 0, 0, 1, 2, 3, 4],
+            list.reverse_iter().cloned().collect::<Vec<_>>()
+        );
+
+        let empty: XorLinkedList<i32> = XorLinkedList::with_capacity(0);
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_with_capacity_drop() {
+        const EXPECTED_DROP_COUNT: i32 = 12;
+        let drop_counter = Rc::new(RefCell::new(0));
+        struct DropImpl {
+            drop_counter: Rc<RefCell<i32>>,
+        }
+        impl Drop for DropImpl {
+            fn drop(&mut self) {
+                *self.drop_counter.borrow_mut() += 1;
+            }
+        }
+
+        let mut list = XorLinkedList::with_capacity(8);
+        for _ in 0..EXPECTED_DROP_COUNT {
+            list.push_back(DropImpl {
+                drop_counter: drop_counter.clone(),
+            });
+        }
+        list.pop_front();
+        list.pop_back();
+        assert_eq!(2, *drop_counter.borrow());
+        for _ in 0..2 {
+            list.push_front(DropImpl {
+                drop_counter: drop_counter.clone(),
+            });
+        }
+        drop(list);
+
+        assert_eq!(EXPECTED_DROP_COUNT + 2, *drop_counter.borrow());
+    }
+
+    #[test]
+    fn test_with_capacity_moved_nodes() {
+        let mut list = XorLinkedList::with_capacity(6);
+        list.extend((0..8).map(|i| i.to_string()));
+
+        let mut tail = list.split_off(3);
+        let mut other = XorLinkedList::from_iter(["a".to_string(), "b".to_string()]);
+        other.append(&mut list);
+        drop(list);
+        tail.sort();
+        other.prepend(&mut tail);
+        drop(tail);
+
+        assert_eq!(
+            vec!["3", "4", "5", "6", "7", "a", "b", "0", "1", "2"],
+            other.iter().collect::<Vec<_>>()
+        );
+        assert_eq!(Some("3".to_string()), other.pop_front());
+        other.push_back("c".to_string());
+        assert_eq!(10, other.len());
+    }
+
+    #[test]
+    fn test_checked_swap() {
+        let mut list = XorLinkedList::from([1, 2, 3]);
+
+        assert!(list.checked_swap(0, 2));
+        assert_eq!(vec![3, 2, 1], list.iter().cloned().collect::<Vec<_>>());
+
+        assert!(list.checked_swap(1, 1));
+        assert_eq!(vec![3, 2, 1], list.iter().cloned().collect::<Vec<_>>());
+
+        assert!(!list.checked_swap(0, 3));
+        assert!(!list.checked_swap(5, 1));
+        assert_eq!(vec![3, 2, 1], list.iter().cloned().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_drain_count_while() {
+        let mut list = XorLinkedList::from([0, 0, 0, 1, 2]);
+
+        assert_eq!(3, list.drain_count_while(|x| *x == 0));
+        assert_eq!(vec![1, 2], list.iter().cloned().collect::<Vec<_>>());
+        assert_eq!(0, list.drain_count_while(|x| *x == 0));
+
+        assert_eq!(2, list.drain_count_while(|_| true));
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn test_iter_from() {
+        let list = XorLinkedList::from_iter(0..5);
+
+        assert_eq!(
+            vec![2, 3, 4],
+            list.iter_from(2).cloned().collect::<Vec<_>>()
+        );
+        assert_eq!(vec![4], list.iter_from(4).cloned().collect::<Vec<_>>());
+        assert_eq!(0, list.iter_from(5).count());
+        assert_eq!(0, list.iter_from(100).count());
+    }
+
+    #[test]
+    fn test_iter_from_mut() {
+        let mut list = XorLinkedList::from_iter(0..5);
+
+        for element in list.iter_from_mut(3) {
+            *element += 100;
+        }
+        assert_eq!(0, list.iter_from_mut(5).count());
+
+        assert_eq!(
+            vec![0, 1, 2, 103, 104],
+            list.iter().cloned().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_swap_ends() {
+        let mut list = XorLinkedList::from([1, 2, 3, 4]);
+
+        list.swap_ends();
+
+        assert_eq!(vec![4, 2, 3, 1], list.iter().cloned().collect::<Vec<_>>());
+        assert_eq!(
+            vec![1, 3, 2, 4],
+            list.reverse_iter().cloned().collect::<Vec<_>>()
+        );
+
+        let mut single = XorLinkedList::from([1]);
+        single.swap_ends();
+        assert_eq!(vec![1], single.iter().cloned().collect::<Vec<_>>());
+
+        let mut empty: XorLinkedList<i32> = XorLinkedList::new();
+        empty.swap_ends();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_at_fraction() {
+        let list = XorLinkedList::from([10, 20, 30, 40, 50]);
+
+        assert_eq!(Some(&10), list.at_fraction(0.0));
+        assert_eq!(Some(&30), list.at_fraction(0.5));
+        assert_eq!(Some(&50), list.at_fraction(1.0));
+        assert_eq!(Some(&10), list.at_fraction(-2.0));
+        assert_eq!(Some(&50), list.at_fraction(3.0));
+        assert_eq!(Some(&50), list.at_fraction(f64::INFINITY));
+        assert_eq!(None, list.at_fraction(f64::NAN));
+
+        let empty: XorLinkedList<i32> = XorLinkedList::new();
+        assert_eq!(None, empty.at_fraction(0.5));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_unique() {
+        let values = [3, 1, 3, 2, 1, 1, 4, 2, 5, 3];
+        let mut list = XorLinkedList::from(values);
+        let mut hinted = XorLinkedList::from(values);
+
+        list.unique();
+        hinted.unique_with_hint(5);
+
+        assert_eq!(
+            vec![3, 1, 2, 4, 5],
+            list.iter().cloned().collect::<Vec<_>>()
+        );
+        assert_eq!(list, hinted);
+        assert_eq!(
+            vec![5, 4, 2, 1, 3],
+            hinted.reverse_iter().cloned().collect::<Vec<_>>()
+        );
+
+        let mut empty: XorLinkedList<i32> = XorLinkedList::new();
+        empty.unique_with_hint(10);
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_try_from_iter_bounded() {
+        let under = XorLinkedList::try_from_iter_bounded(0..3, 5).unwrap();
+        assert_eq!(vec![0, 1, 2], under.iter().cloned().collect::<Vec<_>>());
+
+        let exact = XorLinkedList::try_from_iter_bounded(0..5, 5).unwrap();
+        assert_eq!(
+            vec![0, 1, 2, 3, 4],
+            exact.iter().cloned().collect::<Vec<_>>()
+        );
+
+        assert_eq!(Err(5), XorLinkedList::try_from_iter_bounded(0..6, 5));
+        assert_eq!(Err(0), XorLinkedList::try_from_iter_bounded(0..1, 0));
+        assert_eq!(
+            Ok(XorLinkedList::new()),
+            XorLinkedList::<i32>::try_from_iter_bounded(0..0, 0)
+        );
+    }
+
+    #[test]
+    fn test_try_from_iter_bounded_drops_partial_list() {
+        let drop_counter = Rc::new(RefCell::new(0));
+        struct DropImpl {
+            drop_counter: Rc<RefCell<i32>>,
+        }
+        impl Drop for DropImpl {
+            fn drop(&mut self) {
+                *self.drop_counter.borrow_mut() += 1;
+            }
+        }
+
+        let elements = (0..10).map(|_| DropImpl {
+            drop_counter: drop_counter.clone(),
+        });
+        let result = XorLinkedList::try_from_iter_bounded(elements, 4);
+
+        assert_eq!(Some(4), result.err());
+        assert_eq!(5, *drop_counter.borrow());
+    }
+
+    #[test]
+    fn test_reverse_map() {
+        let list = XorLinkedList::from([1, 2, 3]);
+
+        let mapped = list.reverse_map(|x| x * 10);
+
+        assert_eq!(vec![30, 20, 10], mapped.iter().cloned().collect::<Vec<_>>());
+        assert_eq!(
+            vec![10, 20, 30],
+            mapped.reverse_iter().cloned().collect::<Vec<_>>()
+        );
+        assert!(
+            XorLinkedList::<i32>::new()
+                .reverse_map(|x| x + 1)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_reverse_equivalent_to_relink() {
+        let mut list = XorLinkedList::from_iter(0..6);
+        let old_end = list.end;
+        let relinked = XorLinkedList::from_iter((0..6).rev());
+
+        list.reverse();
+
+        assert_eq!(old_end, unsafe { list.get_ptr_at(0) });
+        assert_eq!(relinked, list);
+        assert!(list.reverse_iter().eq(relinked.reverse_iter()));
+
+        let mut oracle: VecDeque<i32> = (0..6).rev().collect();
+        let mut seed: u32 = 12345;
+        for i in 0..2000 {
+            seed = seed.wrapping_mul(1103515245).wrapping_add(12345);
+            match (seed >> 16) % 6 {
+                0 => {
+                    list.push_front(i);
+                    oracle.push_front(i);
+                }
+                1 => {
+                    list.push_back(i);
+                    oracle.push_back(i);
+                }
+                2 => assert_eq!(oracle.pop_front(), list.pop_front()),
+                3 => assert_eq!(oracle.pop_back(), list.pop_back()),
+                4 => {
+                    list.reverse();
+                    oracle.make_contiguous().reverse();
+                }
+                _ => {
+                    let index = (seed as usize) % (oracle.len() + 1);
+                    list.insert_at(index, i);
+                    oracle.insert(index, i);
+                }
+            }
+
+            assert_eq!(oracle.len(), list.len());
+            assert!(list.iter().eq(oracle.iter()));
+            assert!(list.reverse_iter().eq(oracle.iter().rev()));
+            list.validate();
+        }
+    }
+
+    #[test]
+    fn test_split_every() {
+        let list = XorLinkedList::from_iter(0..7);
+
+        let segments = list.split_every(3);
+
+        assert_eq!(
+            vec![vec![0, 1, 2], vec![3, 4, 5], vec![6]],
+            segments
+                .iter()
+                .map(|segment| segment.iter().cloned().collect::<Vec<_>>())
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![2, 1, 0],
+            segments[0].reverse_iter().cloned().collect::<Vec<_>>()
+        );
+        assert!(XorLinkedList::<i32>::new().split_every(3).is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_split_every_zero() {
+        XorLinkedList::from([1, 2]).split_every(0);
+    }
+
+    #[test]
+    fn test_first_last() {
+        let mut list = XorLinkedList::from([1, 2, 3]);
+
+        assert_eq!(list.peek_front(), list.first());
+        assert_eq!(list.peek_back(), list.last());
+        *list.first_mut().unwrap() = 10;
+        *list.last_mut().unwrap() = 30;
+        assert_eq!(Some(&10), list.first());
+        assert_eq!(Some(&30), list.last());
+
+        let mut empty: XorLinkedList<i32> = XorLinkedList::new();
+        assert_eq!(None, empty.first());
+        assert_eq!(None, empty.last());
+        assert_eq!(None, empty.first_mut());
+        assert_eq!(None, empty.last_mut());
+    }
+
+    #[test]
+    fn test_merge() {
+        let mut list = XorLinkedList::from([1, 3, 5]);
+        list.merge(XorLinkedList::from([2, 4, 6]));
+        assert_eq!(
+            vec![1, 2, 3, 4, 5, 6],
+            list.iter().cloned().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![6, 5, 4, 3, 2, 1],
+            list.reverse_iter().cloned().collect::<Vec<_>>()
+        );
+
+        let mut empty = XorLinkedList::new();
+        empty.merge(XorLinkedList::from([1, 2]));
+        assert_eq!(vec![1, 2], empty.iter().cloned().collect::<Vec<_>>());
+        empty.merge(XorLinkedList::new());
+        assert_eq!(vec![1, 2], empty.iter().cloned().collect::<Vec<_>>());
+
+        let mut first = XorLinkedList::from([(1, 'a'), (2, 'a'), (2, 'b')]);
+        first.merge_by(
+            XorLinkedList::from([(0, 'c'), (2, 'c'), (3, 'c')]),
+            |a, b| a.0.cmp(&b.0),
+        );
+        assert_eq!(
+            vec![(0, 'c'), (1, 'a'), (2, 'a'), (2, 'b'), (2, 'c'), (3, 'c')],
+            first.iter().cloned().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_pairwise_in_place() {
+        let mut list = XorLinkedList::from([1, 4, 9, 16, 25]);
+
+        list.pairwise_in_place(|left, right| *left = right - *left);
+
+        assert_eq!(
+            vec![3, 5, 7, 9, 25],
+            list.iter().cloned().collect::<Vec<_>>()
+        );
+
+        let mut single = XorLinkedList::from([1]);
+        single.pairwise_in_place(|left, right| *left += right);
+        assert_eq!(vec![1], single.iter().cloned().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_get_ext() {
+        fn first_and_third<C: GetExt<i32> + ?Sized>(container: &C) -> (Option<&i32>, Option<&i32>) {
+            (container.try_index(0), container.try_index(2))
+        }
+
+        let list = XorLinkedList::from([1, 2]);
+        let vec = vec![1, 2, 3];
+
+        assert_eq!((Some(&1), None), first_and_third(&list));
+        assert_eq!((Some(&1), Some(&3)), first_and_third(&vec));
+        assert_eq!((Some(&1), Some(&3)), first_and_third(vec.as_slice()));
+    }
+
+    #[test]
+    fn test_into_chunks() {
+        let list = XorLinkedList::from_iter(1..=7);
+
+        let chunks: Vec<_> = list.into_chunks(3).collect();
+
+        assert_eq!(3, chunks.len());
+        assert_eq!(vec![1, 2, 3], chunks[0]);
+        assert_eq!(vec![4, 5, 6], chunks[1]);
+        assert_eq!(vec![7], chunks[2]);
+        assert_eq!(
+            vec![6, 5, 4],
+            chunks[1].reverse_iter().cloned().collect::<Vec<_>>()
+        );
+        assert_eq!(0, XorLinkedList::<i32>::new().into_chunks(2).count());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_into_chunks_zero() {
+        let _ = XorLinkedList::from([1, 2]).into_chunks(0);
+    }
 
-            Some(payload_ref)
+    #[test]
+    fn test_min_max_sum() {
+        let list = XorLinkedList::from([3, 9, 1, 4]);
+        assert_eq!(Some(&9), list.max_element());
+        assert_eq!(Some(&1), list.min_element());
+        assert_eq!(17, list.sum::<i32>());
+
+        let ties = XorLinkedList::from([Tied(2, 'a'), Tied(1, 'b'), Tied(2, 'c'), Tied(1, 'd')]);
+        assert_eq!(Some('c'), ties.max_element().map(|tied| tied.1));
+        assert_eq!(Some('b'), ties.min_element().map(|tied| tied.1));
+
+        let empty: XorLinkedList<i32> = XorLinkedList::new();
+        assert_eq!(None, empty.max_element());
+        assert_eq!(None, empty.min_element());
+        assert_eq!(0, empty.sum::<i32>());
+
+        struct Tied(i32, char);
+        impl PartialEq for Tied {
+            fn eq(&self, other: &Self) -> bool {
+                self.0 == other.0
+            }
+        }
+        impl Eq for Tied {}
+        impl PartialOrd for Tied {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+        impl Ord for Tied {
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.0.cmp(&other.0)
+            }
         }
     }
-}
-
-pub struct ReverseXorLinkedListIter<T> {
-    xor_linked_list: XorLinkedList<T>,
-}
-impl<T> Iterator for ReverseXorLinkedListIter<T> {
-    type Item = T;
 
-    fn next(&mut self) -> Option<Self::Item> {
-        self.xor_linked_list.pop_back()
+    #[test]
+    fn test_count_in_range() {
+        let list = XorLinkedList::from([1, 2, 3, 4, 5]);
+
+        assert_eq!(3, list.count_in_range(&2, &4));
+        assert_eq!(5, list.count_in_range(&0, &9));
+        assert_eq!(1, list.count_in_range(&5, &5));
+        assert_eq!(0, list.count_in_range(&6, &9));
+        assert_eq!(0, list.count_in_range(&4, &2));
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use std::{cell::RefCell, rc::Rc};
+    #[test]
+    fn test_into_chunks_exact() {
+        let list = XorLinkedList::from_iter(0..10);
 
-    use super::*;
+        let (chunks, remainder) = list.into_chunks_exact(3);
 
-    #[test]
-    fn test_push_and_iterate() {
-        let mut list: XorLinkedList<i32> = XorLinkedList::new();
-        list.push_front(2);
-        list.push_front(1);
-        list.push_back(3);
+        assert_eq!(3, chunks.len());
+        assert_eq!(vec![0, 1, 2], chunks[0]);
+        assert_eq!(vec![3, 4, 5], chunks[1]);
+        assert_eq!(vec![6, 7, 8], chunks[2]);
+        assert_eq!(vec![9], remainder);
 
-        let mut items: Vec<i32> = vec![];
-        for i in list {
-            items.push(i);
-        }
+        let (chunks, remainder) = XorLinkedList::from_iter(0..4).into_chunks_exact(2);
+        assert_eq!(2, chunks.len());
+        assert!(remainder.is_empty());
+    }
 
-        assert_eq!(1, items[0]);
-        assert_eq!(2, items[1]);
-        assert_eq!(3, items[2]);
+    #[test]
+    #[should_panic]
+    fn test_into_chunks_exact_zero() {
+        XorLinkedList::from([1, 2]).into_chunks_exact(0);
     }
 
     #[test]
-    fn test_iterate_ref() {
-        let mut list: XorLinkedList<i32> = XorLinkedList::new();
-        list.push_front(3);
-        list.push_front(2);
-        list.push_front(1);
+    fn test_split_when() {
+        let list = XorLinkedList::from([1, 2, 0, 3, 4, 0, 5]);
+
+        let segments = list.split_when(|x| *x == 0);
+
+        assert_eq!(3, segments.len());
+        assert_eq!(vec![1, 2], segments[0]);
+        assert_eq!(vec![0, 3, 4], segments[1]);
+        assert_eq!(vec![0, 5], segments[2]);
+        assert_eq!(
+            vec![4, 3, 0],
+            segments[1].reverse_iter().cloned().collect::<Vec<_>>()
+        );
 
-        let mut items: Vec<i32> = vec![];
-        for i in &list {
-            items.push(*i);
-        }
+        let segments = XorLinkedList::from([0, 0, 1]).split_when(|x| *x == 0);
+        assert_eq!(2, segments.len());
+        assert_eq!(vec![0], segments[0]);
+        assert_eq!(vec![0, 1], segments[1]);
 
-        assert_eq!(1, items[0]);
-        assert_eq!(2, items[1]);
-        assert_eq!(3, items[2]);
+        assert!(
+            XorLinkedList::<i32>::new()
+                .split_when(|x| *x == 0)
+                .is_empty()
+        );
     }
 
     #[test]
-    fn test_iterate_mut_ref() {
-        let mut list: XorLinkedList<i32> = XorLinkedList::new();
-        list.push_front(3);
-        list.push_front(2);
-        list.push_front(1);
-
-        for i in &mut list {
-            *i += 100;
-        }
+    fn test_get_unchecked() {
+        let mut list = XorLinkedList::from_iter(0..7);
 
-        let mut items: Vec<i32> = vec![];
-        for i in &list {
-            items.push(*i);
+        for i in 0..list.len() {
+            unsafe {
+                assert_eq!(i as i32, *list.get_unchecked(i));
+                *list.get_unchecked_mut(i) *= 2;
+            }
         }
 
-        assert_eq!(101, items[0]);
-        assert_eq!(102, items[1]);
-        assert_eq!(103, items[2]);
+        assert_eq!(vec![0, 2, 4, 6, 8, 10, 12], list);
     }
 
+    #[cfg(feature = "std")]
     #[test]
-    fn test_get() {
-        let mut list: XorLinkedList<i32> = XorLinkedList::new();
-        list.push_front(3);
-        list.push_front(2);
-        list.push_front(1);
+    fn test_retain_indices() {
+        let mut list = XorLinkedList::from_iter(0..6);
 
-        assert_eq!(1, *list.get(0).unwrap());
-        assert_eq!(2, *list.get(1).unwrap());
-        assert_eq!(3, *list.get(2).unwrap());
-        assert!(list.get(100).is_none());
+        list.retain_indices(&HashSet::from([0, 2, 4, 10]));
+
+        assert_eq!(vec![0, 2, 4], list);
+        assert_eq!(
+            vec![4, 2, 0],
+            list.reverse_iter().cloned().collect::<Vec<_>>()
+        );
+
+        list.retain_indices(&HashSet::new());
+        assert!(list.is_empty());
     }
 
     #[test]
-    fn test_get_mut() {
-        let mut list: XorLinkedList<i32> = XorLinkedList::new();
-        list.push_front(3);
-        list.push_front(2);
-        list.push_front(1);
+    fn test_first_and_last_index_of() {
+        let list = XorLinkedList::from([1, 2, 1, 3, 1]);
+
+        assert_eq!(Some(0), list.first_index_of(&1));
+        assert_eq!(Some(4), list.last_index_of(&1));
+        assert_eq!(Some(3), list.first_index_of(&3));
+        assert_eq!(Some(3), list.last_index_of(&3));
+        assert_eq!(None, list.first_index_of(&7));
+        assert_eq!(None, list.last_index_of(&7));
+    }
 
-        *list.get_mut(0).unwrap() += 100;
-        *list.get_mut(1).unwrap() += 100;
-        *list.get_mut(2).unwrap() += 100;
+    #[test]
+    fn test_rotate_fuzz() {
+        let mut list = XorLinkedList::new();
+        let mut oracle = VecDeque::new();
+        let mut seed: u32 = 987654321;
+        for i in 0..1000 {
+            seed = seed.wrapping_mul(1103515245).wrapping_add(12345);
+            let random = (seed >> 8) as usize;
+            if random.is_multiple_of(3) || oracle.is_empty() {
+                list.push_back(i);
+                oracle.push_back(i);
+            }
+            let k = random % (oracle.len() + 1);
+            if random.is_multiple_of(2) {
+                list.rotate_left(k);
+                oracle.rotate_left(k);
+            } else {
+                list.rotate_right(k);
+                oracle.rotate_right(k);
+            }
 
-        assert_eq!(101, *list.get(0).unwrap());
-        assert_eq!(102, *list.get(1).unwrap());
-        assert_eq!(103, *list.get(2).unwrap());
-        assert!(list.get_mut(100).is_none());
+            assert!(list.iter().eq(oracle.iter()));
+            assert!(list.reverse_iter().eq(oracle.iter().rev()));
+            list.validate();
+        }
     }
 
     #[test]
-    fn test_index() {
-        let mut list: XorLinkedList<i32> = XorLinkedList::new();
-        list.push_front(3);
-        list.push_front(2);
-        list.push_front(1);
+    fn test_clone_preserves_links() {
+        let mut list = XorLinkedList::from_iter(0..5);
+        list.reverse();
 
-        assert_eq!(1, list[0]);
-        assert_eq!(2, list[1]);
-        assert_eq!(3, list[2]);
+        let mut cloned_list = list.clone();
+        cloned_list.validate();
+
+        assert_eq!(vec![4, 3, 2, 1, 0], cloned_list);
+        assert_eq!(
+            vec![0, 1, 2, 3, 4],
+            cloned_list.reverse_iter().cloned().collect::<Vec<_>>()
+        );
+        cloned_list.push_back(-1);
+        cloned_list.push_front(5);
+        assert_eq!(Some(2), cloned_list.remove_at(3));
+        assert_eq!(vec![5, 4, 3, 1, 0, -1], cloned_list);
+        assert!(XorLinkedList::<i32>::new().clone().is_empty());
     }
 
     #[test]
-    fn test_index_mut() {
-        let mut list: XorLinkedList<i32> = XorLinkedList::new();
-        list.push_front(3);
-        list.push_front(2);
-        list.push_front(1);
+    fn test_clone_panic_drops_partial_list() {
+        let drop_counter = Rc::new(RefCell::new(0));
+        let clone_counter = Rc::new(RefCell::new(0));
+        struct PanickingClone {
+            drop_counter: Rc<RefCell<i32>>,
+            clone_counter: Rc<RefCell<i32>>,
+        }
+        impl Clone for PanickingClone {
+            fn clone(&self) -> Self {
+                *self.clone_counter.borrow_mut() += 1;
+                if *self.clone_counter.borrow() == 3 {
+                    panic!("third clone");
+                }
+                PanickingClone {
+                    drop_counter: self.drop_counter.clone(),
+                    clone_counter: self.clone_counter.clone(),
+                }
+            }
+        }
+        impl Drop for PanickingClone {
+            fn drop(&mut self) {
+                *self.drop_counter.borrow_mut() += 1;
+            }
+        }
 
-        list[0] += 100;
-        list[1] += 100;
-        list[2] += 100;
+        let list = XorLinkedList::from_iter((0..5).map(|_| PanickingClone {
+            drop_counter: drop_counter.clone(),
+            clone_counter: clone_counter.clone(),
+        }));
 
-        assert_eq!(101, list[0]);
-        assert_eq!(102, list[1]);
-        assert_eq!(103, list[2]);
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| list.clone()));
+
+        assert!(result.is_err());
+        assert_eq!(2, *drop_counter.borrow());
+        drop(list);
+        assert_eq!(7, *drop_counter.borrow());
     }
 
     #[test]
-    #[should_panic]
-    fn test_index_out_of_bounds() {
-        let mut list: XorLinkedList<i32> = XorLinkedList::new();
-        list.push_front(3);
-        list.push_front(2);
-        list.push_front(1);
+    fn test_context_windows() {
+        let list = XorLinkedList::from([1, 2, 3]);
+
+        let windows: Vec<_> = list.context_windows().collect();
+
+        assert_eq!(
+            vec![
+                (0, None, &1, Some(&2)),
+                (1, Some(&1), &2, Some(&3)),
+                (2, Some(&2), &3, None)
+            ],
+            windows
+        );
+        assert_eq!(
+            vec![(0, None, &1, None)],
+            XorLinkedList::from([1])
+                .context_windows()
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(0, XorLinkedList::<i32>::new().context_windows().count());
+    }
 
-        list[100];
+    #[test]
+    fn test_swap_remove_first() {
+        let mut list = XorLinkedList::from([1, 2, 3, 4, 5]);
+
+        assert_eq!(Some(2), list.swap_remove_first(|x| x % 2 == 0));
+        assert_eq!(vec![1, 5, 3, 4], list);
+        assert_eq!(Some(4), list.swap_remove_first(|x| *x == 4));
+        assert_eq!(vec![1, 5, 3], list);
+        assert_eq!(None, list.swap_remove_first(|x| *x > 10));
+        assert_eq!(
+            vec![3, 5, 1],
+            list.reverse_iter().cloned().collect::<Vec<_>>()
+        );
     }
 
     #[test]
-    #[should_panic]
-    fn test_mut_index_out_of_bounds() {
-        let mut list: XorLinkedList<i32> = XorLinkedList::new();
-        list.push_front(3);
-        list.push_front(2);
-        list.push_front(1);
+    fn test_zip_with() {
+        let list = XorLinkedList::from([1, 2, 3]);
+        let other = XorLinkedList::from([10, 20]);
 
-        list[100] += 100;
+        let zipped = list.zip_with(other, |a, b| a + b);
+
+        assert_eq!(vec![11, 22], zipped);
+
+        let names = XorLinkedList::from(["a", "b"]);
+        let counts = XorLinkedList::from([1, 2, 3]);
+        assert_eq!(
+            vec![String::from("a"), String::from("bb")],
+            names.zip_with(counts, |name, count| name.repeat(count))
+        );
     }
 
     #[test]
-    fn test_into_reverse_iter() {
-        let mut list: XorLinkedList<i32> = XorLinkedList::new();
-        list.push_front(2);
-        list.push_front(1);
-        list.push_back(3);
+    fn test_map() {
+        let list = XorLinkedList::from([1, 2, 3]);
 
-        let mut items: Vec<i32> = vec![];
-        for i in list.into_reverse_iter() {
-            items.push(i);
-        }
+        let squares = list.map(|x| x * x);
 
-        assert_eq!(3, items[0]);
-        assert_eq!(2, items[1]);
-        assert_eq!(1, items[2]);
+        assert_eq!(vec![1, 4, 9], squares);
+        assert_eq!(
+            vec![9, 4, 1],
+            squares.reverse_iter().cloned().collect::<Vec<_>>()
+        );
     }
 
     #[test]
-    fn test_peek_front() {
-        let mut list: XorLinkedList<i32> = XorLinkedList::new();
-        assert!(list.peek_front().is_none());
-        list.push_front(3);
-        list.push_front(2);
-        list.push_front(1);
+    fn test_map_panic_drops_elements() {
+        let drop_counter = Rc::new(RefCell::new(0));
+        struct DropImpl {
+            drop_counter: Rc<RefCell<i32>>,
+        }
+        impl Drop for DropImpl {
+            fn drop(&mut self) {
+                *self.drop_counter.borrow_mut() += 1;
+            }
+        }
 
-        assert_eq!(1, *list.peek_front().unwrap());
+        let list = XorLinkedList::from_iter((0..5).map(|_| DropImpl {
+            drop_counter: drop_counter.clone(),
+        }));
+        let mut mapped_count = 0;
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            list.map(|element| {
+                mapped_count += 1;
+                if mapped_count == 3 {
+                    panic!("third element");
+                }
+                element
+            })
+        }));
+
+        assert!(result.is_err());
+        assert_eq!(5, *drop_counter.borrow());
     }
 
+    #[cfg(feature = "std")]
     #[test]
-    fn test_peek_back() {
-        let mut list: XorLinkedList<i32> = XorLinkedList::new();
-        assert!(list.peek_back().is_none());
-        list.push_front(3);
-        list.push_front(2);
-        list.push_front(1);
+    fn test_keep_longest_run() {
+        let list = XorLinkedList::from([
+            (1, 'a'),
+            (2, 'b'),
+            (2, 'c'),
+            (1, 'd'),
+            (1, 'e'),
+            (1, 'f'),
+            (3, 'g'),
+            (2, 'h'),
+            (2, 'i'),
+            (3, 'j'),
+        ]);
+        let mut values = XorLinkedList::from_iter(list.iter().map(|(value, _)| *value));
+
+        values.keep_longest_run();
+
+        assert_eq!(vec![2, 1, 3], values);
+
+        let mut tagged =
+            XorLinkedList::from_iter(list.iter().map(|(value, tag)| Tagged(*value, *tag)));
+        tagged.keep_longest_run();
+        assert_eq!(
+            vec!['b', 'd', 'g'],
+            tagged.iter().map(|tagged| tagged.1).collect::<Vec<_>>()
+        );
 
-        assert_eq!(3, *list.peek_back().unwrap());
+        #[derive(Clone)]
+        struct Tagged(i32, char);
+        impl PartialEq for Tagged {
+            fn eq(&self, other: &Self) -> bool {
+                self.0 == other.0
+            }
+        }
+        impl Eq for Tagged {}
+        impl Hash for Tagged {
+            fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+                self.0.hash(state);
+            }
+        }
     }
 
     #[test]
-    fn test_peek_front_mut() {
-        let mut list: XorLinkedList<i32> = XorLinkedList::new();
-        assert!(list.peek_front_mut().is_none());
-        list.push_front(3);
-        list.push_front(2);
-        list.push_front(1);
-        *list.peek_front_mut().unwrap() += 100;
+    fn test_copy_into_slice() {
+        let list = XorLinkedList::from([1, 2, 3]);
 
-        assert_eq!(101, *list.peek_front().unwrap());
+        let mut larger = [0; 5];
+        assert_eq!(3, list.copy_into_slice(&mut larger));
+        assert_eq!([1, 2, 3, 0, 0], larger);
+
+        let mut smaller = [0; 2];
+        assert_eq!(2, list.copy_into_slice(&mut smaller));
+        assert_eq!([1, 2], smaller);
+
+        assert_eq!(0, list.copy_into_slice(&mut []));
     }
 
     #[test]
-    fn test_peek_back_mut() {
-        let mut list: XorLinkedList<i32> = XorLinkedList::new();
-        assert!(list.peek_back_mut().is_none());
-        list.push_front(3);
-        list.push_front(2);
-        list.push_front(1);
-        *list.peek_back_mut().unwrap() += 100;
-
-        assert_eq!(103, *list.peek_back().unwrap());
+    fn test_validate() {
+        let mut list = XorLinkedList::from_iter(0..10);
+        list.validate();
+        list.remove_at(4);
+        list.rotate_left(3);
+        list.reverse();
+        list.insert_at(2, 42);
+        list.validate();
+        XorLinkedList::<i32>::new().validate();
     }
 
     #[test]
-    fn test_clone() {
-        let mut list: XorLinkedList<i32> = XorLinkedList::new();
-        list.push_front(3);
-        list.push_front(2);
-        list.push_front(1);
+    fn test_validate_detects_corruption() {
+        fn validate_message(list: &XorLinkedList<i32>) -> String {
+            let error = std::panic::catch_unwind(|| list.validate()).unwrap_err();
+            error.downcast_ref::<String>().unwrap().clone()
+        }
+        let mut list = XorLinkedList::from([1, 2, 3]);
 
-        let cloned_list = list.clone();
-        assert_eq!(3, cloned_list.len());
+        list.size = 4;
+        assert!(validate_message(&list).starts_with(
+            "assertion `left == right` failed: forward walk visits 3 nodes but the size is 4"
+        ));
+        list.size = 3;
 
-        for (i, j) in list.into_iter().zip(cloned_list.into_iter()) {
-            assert_eq!(i, j);
-        }
+        let end = replace(&mut list.end, list.start);
+        assert!(validate_message(&list).contains("forward walk does not finish at the end node"));
+        list.end = end;
+
+        list.validate();
     }
 
     #[test]
-    fn test_reverse_iter() {
-        let mut list: XorLinkedList<i32> = XorLinkedList::new();
-        list.push_front(3);
-        list.push_front(2);
-        list.push_front(1);
-
-        let mut iter = list.reverse_iter();
-        assert_eq!(3, *iter.next().unwrap());
-        assert_eq!(2, *iter.next().unwrap());
-        assert_eq!(1, *iter.next().unwrap());
-        assert!(iter.next().is_none());
+    fn test_concat() {
+        let lists = vec![
+            XorLinkedList::from([1, 2]),
+            XorLinkedList::new(),
+            XorLinkedList::from([3]),
+            XorLinkedList::from([4, 5, 6]),
+        ];
+
+        let concatenated = XorLinkedList::concat(lists);
+
+        assert_eq!(6, concatenated.len());
+        assert_eq!(vec![1, 2, 3, 4, 5, 6], concatenated);
+        assert_eq!(
+            vec![6, 5, 4, 3, 2, 1],
+            concatenated.reverse_iter().cloned().collect::<Vec<_>>()
+        );
+        concatenated.validate();
+        assert!(XorLinkedList::<i32>::concat(vec![]).is_empty());
     }
 
     #[test]
-    fn test_reverse_iter_mut() {
-        let mut list: XorLinkedList<i32> = XorLinkedList::new();
-        list.push_front(3);
-        list.push_front(2);
-        list.push_front(1);
+    fn test_for_each_batch() {
+        let list = XorLinkedList::from_iter(1..=7);
+        let mut sums = vec![];
 
-        let mut iter = list.reverse_iter_mut();
-        *iter.next().unwrap() += 300;
-        *iter.next().unwrap() += 200;
-        *iter.next().unwrap() += 100;
-        assert!(iter.next().is_none());
-        drop(iter);
+        list.for_each_batch(3, |batch| sums.push(batch.iter().copied().sum::<i32>()));
 
-        assert_eq!(101, list[0]);
-        assert_eq!(202, list[1]);
-        assert_eq!(303, list[2]);
+        assert_eq!(vec![6, 15, 7], sums);
+
+        let mut batch_count = 0;
+        XorLinkedList::<i32>::new().for_each_batch(3, |_| batch_count += 1);
+        assert_eq!(0, batch_count);
     }
 
     #[test]
-    fn test_extend() {
-        let mut list1: XorLinkedList<i32> = XorLinkedList::new();
-        list1.push_front(3);
-        list1.push_front(2);
-        list1.push_front(1);
+    #[should_panic]
+    fn test_for_each_batch_zero() {
+        XorLinkedList::from([1]).for_each_batch(0, |_| {});
+    }
 
-        let mut list2: XorLinkedList<i32> = XorLinkedList::new();
-        list2.push_front(5);
-        list2.push_front(4);
+    #[test]
+    fn test_rotate_left_returning_old_front() {
+        let mut list = XorLinkedList::from([1, 2, 3, 4, 5]);
 
-        list1.extend(list2);
+        assert_eq!(Some(1), list.rotate_left_returning_old_front(2));
+        assert_eq!(Some(&3), list.peek_front());
+        assert_eq!(Some(3), list.rotate_left_returning_old_front(0));
+        assert_eq!(vec![3, 4, 5, 1, 2], list);
 
-        assert_eq!(5, list1.len());
-        assert_eq!(1, list1[0]);
-        assert_eq!(2, list1[1]);
-        assert_eq!(3, list1[2]);
-        assert_eq!(4, list1[3]);
-        assert_eq!(5, list1[4]);
+        let mut empty: XorLinkedList<i32> = XorLinkedList::new();
+        assert_eq!(None, empty.rotate_left_returning_old_front(0));
     }
 
     #[test]
-    fn test_drop() {
-        const EXPECTED_DROP_COUNT: i32 = 5;
-        let drop_counter = Rc::new(RefCell::new(0));
-        struct DropImpl {
-            drop_counter: Rc<RefCell<i32>>,
-        }
-        impl Drop for DropImpl {
-            fn drop(&mut self) {
-                *self.drop_counter.borrow_mut() += 1;
-            }
-        }
+    fn test_swap_remove() {
+        let mut list = XorLinkedList::from([1, 2, 3, 4, 5]);
+
+        assert_eq!(Some(2), list.swap_remove(1));
+        assert_eq!(vec![1, 5, 3, 4], list);
+        assert_eq!(Some(4), list.swap_remove(3));
+        assert_eq!(vec![1, 5, 3], list);
+        assert_eq!(None, list.swap_remove(3));
+        assert_eq!(Some(1), list.swap_remove(0));
+        assert_eq!(vec![3, 5], list);
+        list.validate();
+    }
 
-        let mut list = XorLinkedList::new();
-        for _ in 0..EXPECTED_DROP_COUNT {
-            list.push_back(DropImpl {
-                drop_counter: drop_counter.clone(),
-            });
+    #[test]
+    fn test_double_ended_ref_iter() {
+        let list = XorLinkedList::from([1, 2, 3, 4, 5]);
+
+        let mut iter = (&list).into_iter();
+        assert_eq!((5, Some(5)), iter.size_hint());
+        assert_eq!(Some(&1), iter.next());
+        assert_eq!(Some(&5), iter.next_back());
+        assert_eq!(Some(&4), iter.next_back());
+        assert_eq!(Some(&2), iter.next());
+        assert_eq!((1, Some(1)), iter.size_hint());
+        assert_eq!(Some(&3), iter.next_back());
+        assert_eq!(None, iter.next());
+        assert_eq!(None, iter.next_back());
+
+        let mut even = XorLinkedList::from([1, 2]);
+        {
+            let mut iter = even.iter();
+            assert_eq!(Some(&2), iter.next_back());
+            assert_eq!(Some(&1), iter.next_back());
+            assert_eq!(None, iter.next());
         }
-        drop(list);
 
-        assert_eq!(EXPECTED_DROP_COUNT, *drop_counter.borrow());
+        assert_eq!(
+            vec![5, 4, 3, 2, 1],
+            list.iter().rev().cloned().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![5, 4, 3],
+            list.iter_from(2).rev().cloned().collect::<Vec<_>>()
+        );
+        assert_eq!(0, list.iter_from(5).rev().count());
+        even.reverse();
+        assert_eq!(vec![1, 2], even.iter().rev().cloned().collect::<Vec<_>>());
     }
 
     #[test]
-    fn test_reverse() {
-        let mut list: XorLinkedList<i32> = XorLinkedList::new();
-        list.push_front(3);
-        list.push_front(2);
-        list.push_front(1);
+    fn test_symmetric_difference_sorted() {
+        let first = XorLinkedList::from([1, 2, 3]);
+        let second = XorLinkedList::from([2, 3, 4]);
+
+        assert_eq!(vec![1, 4], first.symmetric_difference_sorted(&second));
+        assert_eq!(vec![1, 4], second.symmetric_difference_sorted(&first));
+        assert_eq!(
+            vec![0, 1, 2, 3, 5, 7],
+            XorLinkedList::from([1, 3, 5])
+                .symmetric_difference_sorted(&XorLinkedList::from([0, 2, 7]))
+        );
+        assert!(first.symmetric_difference_sorted(&first).is_empty());
+        assert_eq!(
+            first,
+            first.symmetric_difference_sorted(&XorLinkedList::new())
+        );
+    }
 
-        list.reverse();
+    #[test]
+    fn test_intersection_and_union_sorted() {
+        let first = XorLinkedList::from([1, 2, 3]);
+        let second = XorLinkedList::from([2, 3, 4]);
+
+        assert_eq!(vec![2, 3], first.intersection_sorted(&second));
+        assert_eq!(vec![1, 2, 3, 4], first.union_sorted(&second));
+        assert_eq!(vec![1, 2, 3, 4], second.union_sorted(&first));
+
+        let empty = XorLinkedList::new();
+        assert!(first.intersection_sorted(&empty).is_empty());
+        assert_eq!(first, first.union_sorted(&empty));
+        assert_eq!(first, first.intersection_sorted(&first));
+    }
 
-        assert_eq!(3, list[0]);
-        assert_eq!(2, list[1]);
-        assert_eq!(1, list[2]);
+    #[test]
+    fn test_partition() {
+        let list = XorLinkedList::from([1, 2, 3, 4, 5]);
+
+        let (even, odd) = list.partition(|x| x % 2 == 0);
+
+        assert_eq!(2, even.len());
+        assert_eq!(3, odd.len());
+        assert_eq!(vec![2, 4], even);
+        assert_eq!(vec![1, 3, 5], odd);
+        even.validate();
+        odd.validate();
+
+        let mut pooled = XorLinkedList::with_capacity(4);
+        pooled.extend(0..6);
+        let (all, none) = pooled.partition(|_| true);
+        assert_eq!(vec![0, 1, 2, 3, 4, 5], all);
+        assert!(none.is_empty());
     }
 
     #[test]
-    fn test_insert_at() {
-        let mut list: XorLinkedList<i32> = XorLinkedList::new();
-        list.insert_at(0, 4);
-        list.insert_at(0, 1);
-        list.insert_at(1, 2);
-        list.insert_at(2, 3);
-        list.insert_at(4, 7);
-        list.insert_at(4, 6);
-        list.insert_at(4, 5);
+    fn test_any_all_find() {
+        let list = XorLinkedList::from([1, 2, 3, 4]);
+
+        assert!(list.any(|x| *x == 3));
+        assert!(!list.any(|x| *x > 4));
+        assert!(list.all(|x| *x > 0));
+        assert!(!list.all(|x| x % 2 == 0));
+        assert_eq!(Some(&2), list.find(|x| x % 2 == 0));
+        assert_eq!(None, list.find(|x| *x > 4));
+
+        let empty: XorLinkedList<i32> = XorLinkedList::new();
+        assert!(!empty.any(|_| true));
+        assert!(empty.all(|_| false));
+        assert_eq!(None, empty.find(|_| true));
+    }
 
-        assert_eq!(7, list.len());
-        assert_eq!(1, list[0]);
-        assert_eq!(2, list[1]);
-        assert_eq!(3, list[2]);
-        assert_eq!(4, list[3]);
-        assert_eq!(5, list[4]);
-        assert_eq!(6, list[5]);
-        assert_eq!(7, list[6]);
+    #[test]
+    fn test_drain_front_to_vec() {
+        let mut list = XorLinkedList::from([1, 2, 3, 4, 5]);
+
+        assert_eq!(vec![1, 2, 3], list.drain_front_to_vec(3));
+        assert_eq!(vec![4, 5], list);
+        assert_eq!(vec![4, 5], list.drain_front_to_vec(10));
+        assert!(list.is_empty());
+        assert!(list.drain_front_to_vec(2).is_empty());
     }
 
     #[test]
-    #[should_panic]
-    fn test_insert_panic() {
-        let mut list: XorLinkedList<i32> = XorLinkedList::new();
-        list.insert_at(0, 1);
-        list.insert_at(3, 2);
+    fn test_window_reduce() {
+        let list = XorLinkedList::from([1.0, 2.0, 3.0, 4.0, 5.0]);
+
+        let means = list.window_reduce(3, |window| {
+            window.iter().copied().sum::<f64>() / window.len() as f64
+        });
+
+        assert_eq!(list.len() - 3 + 1, means.len());
+        assert_eq!(vec![2.0, 3.0, 4.0], means);
+        assert!(list.window_reduce(6, |window| window.len()).is_empty());
+        assert_eq!(5, list.window_reduce(1, |window| window.len()).len());
     }
 
     #[test]
-    fn test_insert_at_many_near_back() {
-        let mut list = XorLinkedList::new();
+    #[should_panic]
+    fn test_window_reduce_zero() {
+        XorLinkedList::from([1]).window_reduce(0, |window| window.len());
+    }
 
-        for i in 0..10 {
-            list.push_back(i);
-        }
+    #[test]
+    fn test_push_slice() {
+        let mut list = XorLinkedList::from([4, 5]);
 
-        for i in 0..20 {
-            let idx = list.len() - 1;
-            list.insert_at(idx, 100 + i);
-        }
+        list.push_front_slice(&[1, 2, 3]);
+        list.push_back_slice(&[6, 7]);
+        list.push_front_slice(&[]);
+        list.push_back_slice(&[]);
 
-        let collected: Vec<_> = list.into_iter().collect();
+        assert_eq!(vec![1, 2, 3, 4, 5, 6, 7], list);
 
-        assert_eq!(collected.first(), Some(&0));
-        assert_eq!(collected.last(), Some(&9));
-        assert_eq!(collected.len(), 30);
+        let mut empty = XorLinkedList::new();
+        empty.push_front_slice(&[1, 2]);
+        assert_eq!(vec![1, 2], empty);
+    }
 
-        let mut sorted = collected.clone();
-        sorted.sort();
-        sorted.dedup();
-        assert_eq!(sorted.len(), 30);
+    #[test]
+    fn test_splice() {
+        let mut list = XorLinkedList::from_iter(0..10);
+
+        let removed: Vec<_> = list.splice(4..7, [100, 200]).collect();
+
+        assert_eq!(vec![4, 5, 6], removed);
+        assert_eq!(vec![0, 1, 2, 3, 100, 200, 7, 8, 9], list);
+        list.validate();
+
+        assert_eq!(0, list.splice(0..0, [-1]).count());
+        assert_eq!(vec![8, 9], list.splice(8.., []).collect::<Vec<_>>());
+        assert_eq!(vec![-1, 0], list.splice(..2, [50]).collect::<Vec<_>>());
+        assert_eq!(vec![50, 1, 2, 3, 100, 200, 7], list);
+        assert_eq!(0, list.splice(7.., [300]).count());
+        assert_eq!(
+            vec![300, 7, 200, 100, 3, 2, 1, 50],
+            list.reverse_iter().cloned().collect::<Vec<_>>()
+        );
+        list.validate();
     }
 
     #[test]
-    fn test_insert_at_forward_backward_consistency() {
-        let mut list = XorLinkedList::new();
+    #[should_panic]
+    fn test_splice_out_of_bounds() {
+        let _ = XorLinkedList::from([1, 2]).splice(1..3, []);
+    }
 
-        for i in 0..15 {
-            list.push_back(i);
+    #[test]
+    fn test_clear_retaining() {
+        fn node_addresses<T>(list: &XorLinkedList<T>) -> std::collections::HashSet<usize> {
+            (0..list.len())
+                .map(|index| unsafe { list.get_ptr_at(index) }.addr())
+                .collect()
         }
-
-        let insert_positions = [7, 3, 10, 5, 12, 1, 8];
-        for (i, &pos) in insert_positions.iter().enumerate() {
-            list.insert_at(pos, 1000 + i);
+        let drop_counter = Rc::new(RefCell::new(0));
+        struct DropImpl {
+            drop_counter: Rc<RefCell<i32>>,
+        }
+        impl Drop for DropImpl {
+            fn drop(&mut self) {
+                *self.drop_counter.borrow_mut() += 1;
+            }
         }
+        let new_element = || DropImpl {
+            drop_counter: drop_counter.clone(),
+        };
 
-        let forward: Vec<_> = (&list).into_iter().cloned().collect();
+        let mut list = XorLinkedList::with_capacity(2);
+        list.extend((0..5).map(|_| new_element()));
+        let addresses = node_addresses(&list);
 
-        let backward: Vec<_> = list.reverse_iter().cloned().collect();
+        list.clear_retaining();
 
-        let mut forward_reversed = forward.clone();
-        forward_reversed.reverse();
+        assert!(list.is_empty());
+        assert_eq!(5, *drop_counter.borrow());
 
-        assert_eq!(forward_reversed, backward);
+        list.extend((0..5).map(|_| new_element()));
+        list.validate();
+        assert_eq!(addresses, node_addresses(&list));
+
+        list.clear_retaining();
+        list.push_back(new_element());
+        drop(list);
+        assert_eq!(11, *drop_counter.borrow());
+
+        let mut unpooled = XorLinkedList::from([1, 2, 3]);
+        unpooled.clear_retaining();
+        assert!(unpooled.is_empty());
+        unpooled.push_back(4);
+        assert_eq!(vec![4], unpooled);
     }
 
     #[test]
-    fn test_insert_at_stress_random_positions() {
-        let mut list = XorLinkedList::new();
+    fn test_get_or() {
+        let list = XorLinkedList::from([1, 2, 3]);
 
-        for i in 0..50 {
-            let pos = if list.is_empty() {
-                0
-            } else {
-                (i * 7) % list.len()
-            };
-            list.insert_at(pos, i);
-        }
+        assert_eq!(&2, list.get_or(1, &0));
+        assert_eq!(&0, list.get_or(3, &0));
+        assert_eq!(3, list.get_cloned_or(2, -1));
+        assert_eq!(-1, list.get_cloned_or(10, -1));
+    }
 
-        let forward: Vec<_> = (&list).into_iter().cloned().collect();
-        let backward: Vec<_> = list.reverse_iter().cloned().collect();
+    #[test]
+    fn test_double_ended_reverse_iter() {
+        let list = XorLinkedList::from([1, 2, 3, 4]);
 
-        let mut reversed_forward = forward.clone();
-        reversed_forward.reverse();
+        assert_eq!(Some(&4), list.reverse_iter().next());
+        assert_eq!(Some(&1), list.reverse_iter().next_back());
+        assert_eq!(4, list.reverse_iter().len());
 
-        assert_eq!(reversed_forward, backward);
+        let mut iter = list.reverse_iter();
+        assert_eq!(Some(&4), iter.next());
+        assert_eq!(Some(&1), iter.next_back());
+        assert_eq!(2, iter.len());
+        assert_eq!(vec![&2, &3], iter.rev().collect::<Vec<_>>());
+        assert_eq!(2, list.iter_from(2).len());
     }
 
     #[test]
-    fn test_insert_at_backward_off_by_one_detection() {
-        let mut list = XorLinkedList::new();
+    fn test_cycle() {
+        let mut list = XorLinkedList::from([1, 2, 3, 4]);
 
-        for i in 0..8 {
-            list.push_back(i);
+        list.cycle_front_to_back();
+        assert_eq!(vec![2, 3, 4, 1], list);
+        for _ in 0..3 {
+            list.cycle_front_to_back();
         }
+        assert_eq!(vec![1, 2, 3, 4], list);
+
+        list.cycle_back_to_front();
+        assert_eq!(vec![4, 1, 2, 3], list);
+        list.validate();
+
+        let mut empty: XorLinkedList<i32> = XorLinkedList::new();
+        empty.cycle_front_to_back();
+        empty.cycle_back_to_front();
+        let mut single = XorLinkedList::from([1]);
+        single.cycle_back_to_front();
+        assert_eq!(vec![1], single);
+    }
 
-        list.insert_at(1, 100);
-        list.insert_at(3, 200);
-        list.insert_at(5, 300);
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_bincode() {
+        const LEN: i32 = 100_000;
+        let list = XorLinkedList::from_iter(0..LEN);
 
-        let idx = list.len() / 2 + 1;
-        list.insert_at(idx, 999);
+        let serialized = bincode::serialize(&list).unwrap();
+        let deserialized: XorLinkedList<i32> = bincode::deserialize(&serialized).unwrap();
 
-        assert_eq!(list[idx], 999);
+        assert_eq!(
+            size_of::<u64>() + LEN as usize * size_of::<i32>(),
+            serialized.len()
+        );
+        assert_eq!(list, deserialized);
+        deserialized.validate();
 
-        assert_eq!(list[idx - 1], 300);
-        assert_eq!(list[idx + 1], 3);
+        let empty: XorLinkedList<String> =
+            bincode::deserialize(&bincode::serialize(&XorLinkedList::<String>::new()).unwrap())
+                .unwrap();
+        assert!(empty.is_empty());
     }
 
     #[test]
-    fn test_remove_at_ends() {
-        let mut list: XorLinkedList<i32> = XorLinkedList::new();
-        assert!(list.remove_at(0).is_none());
-        assert!(list.remove_at(1).is_none());
-        assert_eq!(0, list.len());
+    fn test_is_sorted() {
+        assert!(XorLinkedList::from([1, 2, 3, 5]).is_sorted());
+        assert!(XorLinkedList::from([1, 2, 2, 3]).is_sorted());
+        assert!(!XorLinkedList::from([1, 3, 2]).is_sorted());
+        assert!(!XorLinkedList::from([1.0, f64::NAN]).is_sorted());
+        assert!(XorLinkedList::<i32>::new().is_sorted());
+        assert!(XorLinkedList::from([1]).is_sorted());
+
+        let descending = XorLinkedList::from([3, 2, 2, 1]);
+        assert!(descending.is_sorted_by(|a, b| b.partial_cmp(a)));
+        assert!(!descending.is_sorted());
+    }
 
-        list.push_back(1);
-        list.push_back(2);
-        assert_eq!(1, list.remove_at(0).unwrap());
-        assert_eq!(1, list.len());
+    #[test]
+    fn test_count_by_traversal() {
+        let mut list = XorLinkedList::new();
+        assert_eq!(0, list.count_by_traversal());
+
+        list.extend(0..10);
+        list.push_front(-1);
+        list.insert_at(5, 100);
+        list.remove_at(2);
+        list.pop_back();
+        list.retain_range(.., |x| x % 3 != 0);
+        list.insert_sorted(4);
+        assert_eq!(list.len(), list.count_by_traversal());
+
+        list.size += 1;
+        assert_ne!(list.len(), list.count_by_traversal());
+        list.size -= 1;
+    }
 
-        list.push_back(3);
-        assert_eq!(3, list.remove_at(1).unwrap());
-        assert_eq!(1, list.len());
-        assert_eq!(2, list[0]);
+    #[test]
+    fn test_from_iter_rev() {
+        let list = XorLinkedList::from_iter_rev([1, 2, 3]);
+
+        assert_eq!(vec![3, 2, 1], list);
+        assert_eq!(
+            vec![1, 2, 3],
+            list.reverse_iter().cloned().collect::<Vec<_>>()
+        );
+        assert!(XorLinkedList::<i32>::from_iter_rev([]).is_empty());
     }
 
     #[test]
-    fn test_remove_at_middle() {
-        let mut list: XorLinkedList<i32> = XorLinkedList::new();
-        for i in 0..10 {
-            list.push_back(i);
-        }
+    fn test_split_take_while() {
+        let mut list = XorLinkedList::from([2, 4, 6, 3, 8]);
 
-        assert_eq!(8, list.remove_at(8).unwrap());
-        assert_eq!(9, list.len());
+        let evens = list.split_take_while(|x| x % 2 == 0);
 
-        assert_eq!(1, list.remove_at(1).unwrap());
-        assert_eq!(8, list.len());
+        assert_eq!(vec![2, 4, 6], evens);
+        assert_eq!(vec![3, 8], list);
+        evens.validate();
+        list.validate();
 
-        assert_eq!(0, list[0]);
-        assert_eq!(2, list[1]);
-        assert_eq!(3, list[2]);
-        assert_eq!(4, list[3]);
-        assert_eq!(5, list[4]);
-        assert_eq!(6, list[5]);
-        assert_eq!(7, list[6]);
-        assert_eq!(9, list[7]);
+        assert!(list.split_take_while(|x| x % 2 == 0).is_empty());
+        assert_eq!(vec![3, 8], list.split_take_while(|_| true));
+        assert!(list.is_empty());
     }
 
     #[test]
-    fn test_clear() {
-        let mut list = XorLinkedList::new();
-        list.clear();
-        assert_eq!(0, list.size);
+    fn test_debug() {
+        let list = XorLinkedList::from([1, 2, 3]);
 
-        list.push_back(1);
-        list.push_back(2);
-        list.push_back(3);
+        assert_eq!("[1, 2, 3]", format!("{list:?}"));
+        #[cfg(not(feature = "debug-internals"))]
+        assert_eq!(format!("{:#?}", vec![1, 2, 3]), format!("{list:#?}"));
+    }
 
-        list.clear();
-        assert_eq!(0, list.size);
-        assert!(list.peek_back().is_none());
+    #[cfg(feature = "debug-internals")]
+    #[test]
+    fn test_debug_internals() {
+        let list = XorLinkedList::from([11, 22, 33]);
+
+        let formatted = format!("{list:#?}");
+
+        assert_eq!(3, formatted.matches("xor_ptr").count());
+        assert!(formatted.contains("size: 3"));
+        assert!(formatted.contains("payload: 11"));
+        assert!(formatted.contains("payload: 22"));
+        assert!(formatted.contains("payload: 33"));
+        assert!(formatted.find("payload: 11") < formatted.find("payload: 33"));
+        assert!(formatted.contains(&format!("address: {:#?}", list.start)));
+        assert_eq!("[11, 22, 33]", format!("{list:?}"));
     }
 
     #[test]
-    fn test_equals() {
-        let mut list1 = XorLinkedList::new();
-        list1.push_back(1);
-        list1.push_back(2);
-        let mut list2 = list1.clone();
-        assert_eq!(list1, list2);
+    fn test_with_capacity_sort_does_not_allocate_per_element() {
+        let mut list = XorLinkedList::with_capacity(1000);
+        list.extend((0..1000).rev());
 
-        list2.push_back(3);
-        assert_ne!(list1, list2);
+        let allocations = count_allocations(|| list.sort());
 
-        list1.push_back(3);
-        assert_eq!(list1, list2);
+        assert!(allocations < 20, "sort made {allocations} allocations");
+        assert!(list.iter().copied().eq(0..1000));
+        list.validate();
+    }
 
-        list1[1] = 5;
-        assert_ne!(list1, list2);
+    #[test]
+    fn test_with_capacity_concat_shares_one_registry() {
+        let lists: Vec<_> = (0..20)
+            .map(|i| {
+                let mut list = XorLinkedList::with_capacity(3);
+                list.extend(i * 3..i * 3 + 3);
+                list
+            })
+            .collect();
+
+        let mut concatenated = XorLinkedList::concat(lists);
+
+        let registry = concatenated.arena.chunks.clone().unwrap();
+        {
+            let chunks = &registry.borrow().chunks;
+            assert_eq!(20, chunks.len());
+            assert!(chunks.is_sorted_by_key(|chunk| chunk.first_slot().addr()));
+        }
+        assert!(concatenated.iter().copied().eq(0..60));
+
+        let tail = concatenated.split_off(30);
+        assert!(Rc::ptr_eq(&registry, tail.arena.chunks.as_ref().unwrap()));
+        assert_eq!(Some(0), concatenated.pop_front());
+        concatenated.push_back(100);
+        assert_eq!(Some(100), concatenated.pop_back());
+        assert_eq!(29, concatenated.len());
+        concatenated.validate();
     }
 
     #[test]
-    fn test_from_iterator() {
-        let list = XorLinkedList::from_iter([1, 2, 3].into_iter());
+    fn test_window_reduce_matches_slice_windows() {
+        let items: Vec<i32> = (0..50).collect();
+        let list = XorLinkedList::from_iter(items.iter().copied());
 
-        assert_eq!(3, list.len());
-        assert_eq!(1, list[0]);
-        assert_eq!(2, list[1]);
-        assert_eq!(3, list[2]);
+        for window in 1..=52 {
+            let sums = list.window_reduce(window, |window| window.iter().copied().sum::<i32>());
+
+            let expected: Vec<i32> = items.windows(window).map(|w| w.iter().sum()).collect();
+            assert_eq!(expected, sums);
+        }
     }
 
-    #[cfg(feature = "serde")]
     #[test]
-    fn test_serde() {
-        let mut list = XorLinkedList::new();
-        list.push_back(1);
-        list.push_back(2);
-        list.push_back(3);
+    fn test_split_when_calls_predicate_once_per_element() {
+        let list = XorLinkedList::from([1, 2, 3, 4, 5, 6, 7]);
+        let mut seen = Vec::new();
+
+        let segments = list.split_when(|x| {
+            seen.push(*x);
+            seen.len() % 3 == 0
+        });
+
+        assert_eq!(vec![1, 2, 3, 4, 5, 6, 7], seen);
+        assert_eq!(3, segments.len());
+        assert_eq!(vec![1, 2], segments[0]);
+        assert_eq!(vec![3, 4, 5], segments[1]);
+        assert_eq!(vec![6, 7], segments[2]);
+    }
 
-        let serialized = serde_json::to_string(&list).unwrap();
+    #[test]
+    fn test_with_capacity_reuses_slots_of_split_lists() {
+        fn node_addresses<T>(list: &XorLinkedList<T>) -> std::collections::HashSet<usize> {
+            (0..list.len())
+                .map(|index| unsafe { list.get_ptr_at(index) }.addr())
+                .collect()
+        }
 
-        let deserialized: XorLinkedList<i32> = serde_json::from_str(&serialized).unwrap();
+        let mut list = XorLinkedList::with_capacity(8);
+        list.extend(0..8);
+        let addresses = node_addresses(&list);
+
+        list.truncate(0);
+        list.extend(0..8);
+        list.validate();
+        assert_eq!(addresses, node_addresses(&list));
+
+        let tail = list.split_off(3);
+        drop(tail);
+        list.extend(3..8);
+        list.validate();
+        assert_eq!(addresses, node_addresses(&list));
+        assert!(list.iter().copied().eq(0..8));
+
+        let mut tail = list.split_off(4);
+        tail.clear();
+        tail.extend(4..8);
+        list.append(&mut tail);
+        assert_eq!(addresses, node_addresses(&list));
+        assert!(list.iter().copied().eq(0..8));
+    }
 
-        assert_eq!(list, deserialized);
+    #[test]
+    fn test_splice_reuses_slots() {
+        let mut list = XorLinkedList::with_capacity(8);
+        list.extend(0..8);
+        let addresses: std::collections::HashSet<usize> = (0..list.len())
+            .map(|index| unsafe { list.get_ptr_at(index) }.addr())
+            .collect();
+
+        assert_eq!(vec![0, 1, 2, 3], list.splice(0..4, []).collect::<Vec<_>>());
+        list.splice(0..0, [10, 11, 12, 13]).for_each(drop);
+
+        assert_eq!(vec![10, 11, 12, 13, 4, 5, 6, 7], list);
+        assert_eq!(
+            addresses,
+            (0..list.len())
+                .map(|index| unsafe { list.get_ptr_at(index) }.addr())
+                .collect()
+        );
+
+        list.splice(2..6, [20, 21, 22, 23]).for_each(drop);
+
+        assert_eq!(vec![10, 11, 20, 21, 22, 23, 6, 7], list);
+        assert_eq!(
+            addresses,
+            (0..list.len())
+                .map(|index| unsafe { list.get_ptr_at(index) }.addr())
+                .collect()
+        );
+        list.validate();
     }
 }